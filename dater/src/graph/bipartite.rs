@@ -0,0 +1,132 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Checks whether the nodes can be split into two sets with every edge
+    /// running between the sets.
+    ///
+    /// Edge directions are ignored. Each connected component is 2-colored with a
+    /// breadth-first search, and the graph is bipartite if no edge joins two nodes
+    /// of the same color. Equivalently, the graph contains no cycle of odd length.
+    /// An empty graph is bipartite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let n3 = graph.insert(3).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// graph.connect(n2, (), n3);
+    /// assert!(graph.is_bipartite());
+    ///
+    /// graph.connect(n3, (), n1);
+    /// assert!(!graph.is_bipartite());
+    /// ```
+    pub fn is_bipartite(&self) -> bool {
+        let mut adjacency: HashMap<GraphNodeId, Vec<GraphNodeId>> = HashMap::new();
+        for edge in self.edges.values() {
+            adjacency.entry(edge.from).or_default().push(edge.to);
+            adjacency.entry(edge.to).or_default().push(edge.from);
+        }
+
+        let mut colors: HashMap<GraphNodeId, bool> = HashMap::new();
+        for &start in self.nodes.keys() {
+            if colors.contains_key(&start) {
+                continue;
+            }
+
+            colors.insert(start, false);
+            let mut frontier = VecDeque::from([start]);
+            while let Some(current) = frontier.pop_front() {
+                let color = colors[&current];
+                for &next in adjacency.get(&current).into_iter().flatten() {
+                    match colors.get(&next) {
+                        Some(&next_color) if next_color == color => return false,
+                        Some(_) => {}
+                        None => {
+                            colors.insert(next, !color);
+                            frontier.push_back(next);
+                        }
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycle(graph: &mut Graph<usize, ()>, length: usize) {
+        let ids: Vec<GraphNodeId> = (0..length).map(|n| graph.insert(n).unwrap()).collect();
+        for i in 0..length {
+            graph.connect(ids[i], (), ids[(i + 1) % length]);
+        }
+    }
+
+    #[test]
+    fn test_path_is_bipartite() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        let ids: Vec<GraphNodeId> = (0..5).map(|n| graph.insert(n).unwrap()).collect();
+        for pair in ids.windows(2) {
+            graph.connect(pair[0], (), pair[1]);
+        }
+
+        assert!(graph.is_bipartite());
+    }
+
+    #[test]
+    fn test_triangle_is_not_bipartite() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        cycle(&mut graph, 3);
+
+        assert!(!graph.is_bipartite());
+    }
+
+    #[test]
+    fn test_even_cycle_is_bipartite() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        cycle(&mut graph, 6);
+
+        assert!(graph.is_bipartite());
+    }
+
+    #[test]
+    fn test_direction_is_ignored() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        let a = graph.insert(0).unwrap();
+        let b = graph.insert(1).unwrap();
+        let c = graph.insert(2).unwrap();
+        graph.connect(a, (), b);
+        graph.connect(a, (), c);
+        graph.connect(c, (), b);
+
+        assert!(!graph.is_bipartite(), "An odd cycle in any direction");
+    }
+
+    #[test]
+    fn test_disconnected_components() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        cycle(&mut graph, 4);
+        assert!(graph.is_bipartite());
+
+        cycle(&mut graph, 5);
+        assert!(!graph.is_bipartite(), "Every component must be bipartite");
+    }
+
+    #[test]
+    fn test_trivial_graphs() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        assert!(graph.is_bipartite());
+
+        graph.insert(0);
+        assert!(graph.is_bipartite());
+    }
+}
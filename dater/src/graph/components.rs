@@ -0,0 +1,294 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::*;
+use crate::{LinearData, Stack};
+
+impl<T, E> Graph<T, E> {
+    /// Partitions the nodes into weakly connected components.
+    ///
+    /// Edge directions are ignored, so two nodes are in the same component if
+    /// they are joined by any chain of edges. Isolated nodes form components of
+    /// their own. Each component is found with a breadth-first search.
+    ///
+    /// # Returns
+    ///
+    /// One `Vec<GraphNodeId>` per component, ordered by size from largest to
+    /// smallest. Node IDs within a component are sorted, and components of equal
+    /// size are ordered by their smallest node ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let n3 = graph.insert(3).unwrap();
+    /// graph.connect(n2, (), n1);
+    ///
+    /// assert_eq!(graph.connected_components(), vec![vec![n1, n2], vec![n3]]);
+    /// ```
+    pub fn connected_components(&self) -> Vec<Vec<GraphNodeId>> {
+        let mut adjacency: HashMap<GraphNodeId, Vec<GraphNodeId>> = HashMap::new();
+        for edge in self.edges.values() {
+            adjacency.entry(edge.from).or_default().push(edge.to);
+            adjacency.entry(edge.to).or_default().push(edge.from);
+        }
+
+        let mut starts: Vec<GraphNodeId> = self.nodes.keys().copied().collect();
+        starts.sort();
+
+        let mut visited: HashSet<GraphNodeId> = HashSet::new();
+        let mut components = Vec::new();
+        for start in starts {
+            if !visited.insert(start) {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut frontier = VecDeque::from([start]);
+            while let Some(current) = frontier.pop_front() {
+                component.push(current);
+                for &next in adjacency.get(&current).into_iter().flatten() {
+                    if visited.insert(next) {
+                        frontier.push_back(next);
+                    }
+                }
+            }
+
+            component.sort();
+            components.push(component);
+        }
+
+        // Stable, so equal-sized components stay ordered by their first node
+        components.sort_by_key(|component| std::cmp::Reverse(component.len()));
+        components
+    }
+
+    /// Partitions the nodes into strongly connected components.
+    ///
+    /// Two nodes are in the same component if each can be reached from the other
+    /// by following edge directions, so every directed cycle lies within a single
+    /// component. A node that isn't on any cycle forms a component of its own.
+    ///
+    /// Uses Kosaraju's algorithm: a depth-first search records the order in which
+    /// nodes finish, then a second search over the reversed edges, taking nodes
+    /// in reverse finishing order, collects one component at a time. Both searches
+    /// use an explicit [`Stack`] rather than recursion, so long chains cannot
+    /// overflow the call stack.
+    ///
+    /// # Returns
+    ///
+    /// One `Vec<GraphNodeId>` per component, ordered by size from largest to
+    /// smallest. Node IDs within a component are sorted, and components of equal
+    /// size are ordered by their smallest node ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let n3 = graph.insert(3).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// graph.connect(n2, (), n1);
+    /// graph.connect(n2, (), n3);
+    ///
+    /// assert_eq!(graph.strongly_connected_components(), vec![vec![n1, n2], vec![n3]]);
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<Vec<GraphNodeId>> {
+        let adjacency = self.adjacency();
+        let mut reversed: HashMap<GraphNodeId, Vec<GraphNodeId>> = HashMap::new();
+        for edge in self.edges.values() {
+            reversed.entry(edge.to).or_default().push(edge.from);
+        }
+
+        let mut starts: Vec<GraphNodeId> = self.nodes.keys().copied().collect();
+        starts.sort();
+
+        // First pass: record nodes in the order their searches finish
+        let mut visited: HashSet<GraphNodeId> = HashSet::new();
+        let mut finished = Vec::with_capacity(starts.len());
+        for &start in &starts {
+            if !visited.insert(start) {
+                continue;
+            }
+
+            // Each entry is a node and the index of the next successor to explore
+            let mut pending = Stack::new();
+            pending.push((start, 0));
+            while let Some((current, index)) = pending.pop() {
+                match adjacency[&current].get(index) {
+                    Some(&next) => {
+                        pending.push((current, index + 1));
+                        if visited.insert(next) {
+                            pending.push((next, 0));
+                        }
+                    }
+                    None => finished.push(current),
+                }
+            }
+        }
+
+        // Second pass: each search over the reversed edges is one component
+        let mut assigned: HashSet<GraphNodeId> = HashSet::new();
+        let mut components = Vec::new();
+        for &start in finished.iter().rev() {
+            if !assigned.insert(start) {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut pending = Stack::new();
+            pending.push(start);
+            while let Some(current) = pending.pop() {
+                component.push(current);
+                for &next in reversed.get(&current).into_iter().flatten() {
+                    if assigned.insert(next) {
+                        pending.push(next);
+                    }
+                }
+            }
+
+            component.sort();
+            components.push(component);
+        }
+
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connected_components_single_component() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n3, (), n2);
+
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![n1, n2, n3]],
+            "Edge direction should be ignored"
+        );
+    }
+
+    #[test]
+    fn test_connected_components_isolated_nodes() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+
+        assert_eq!(graph.connected_components(), vec![vec![n1], vec![n2]]);
+    }
+
+    #[test]
+    fn test_connected_components_mixed() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let isolated = graph.insert(0).unwrap();
+        let a1 = graph.insert(1).unwrap();
+        let a2 = graph.insert(2).unwrap();
+        let b1 = graph.insert(3).unwrap();
+        let b2 = graph.insert(4).unwrap();
+        let b3 = graph.insert(5).unwrap();
+        graph.connect(a1, (), a2);
+        graph.connect(b1, (), b2);
+        graph.connect(b3, (), b2);
+
+        let components = graph.connected_components();
+        assert_eq!(
+            components,
+            vec![vec![b1, b2, b3], vec![a1, a2], vec![isolated]],
+            "Components should be ordered largest first"
+        );
+    }
+
+    #[test]
+    fn test_connected_components_empty_graph() {
+        let graph: Graph<i32, ()> = Graph::new();
+        assert!(graph.connected_components().is_empty());
+    }
+
+    #[test]
+    fn test_strongly_connected_single_cycle() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let ids: Vec<GraphNodeId> = (0..5).map(|n| graph.insert(n).unwrap()).collect();
+        for i in 0..5 {
+            graph.connect(ids[i], (), ids[(i + 1) % 5]);
+        }
+        graph.connect(ids[0], (), ids[3]);
+
+        assert_eq!(graph.strongly_connected_components(), vec![ids]);
+    }
+
+    #[test]
+    fn test_strongly_connected_dag() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let a = graph.insert(1).unwrap();
+        let b = graph.insert(2).unwrap();
+        let c = graph.insert(3).unwrap();
+        let d = graph.insert(4).unwrap();
+        graph.connect(a, (), b);
+        graph.connect(a, (), c);
+        graph.connect(b, (), d);
+        graph.connect(c, (), d);
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![a], vec![b], vec![c], vec![d]],
+            "Every node of a DAG is its own component"
+        );
+    }
+
+    #[test]
+    fn test_strongly_connected_two_components() {
+        let mut graph: Graph<&str, ()> = Graph::new();
+        let a1 = graph.insert("a1").unwrap();
+        let a2 = graph.insert("a2").unwrap();
+        let b1 = graph.insert("b1").unwrap();
+        let b2 = graph.insert("b2").unwrap();
+        let b3 = graph.insert("b3").unwrap();
+        let lone = graph.insert("lone").unwrap();
+        graph.connect(a1, (), a2);
+        graph.connect(a2, (), a1);
+        graph.connect(b1, (), b2);
+        graph.connect(b2, (), b3);
+        graph.connect(b3, (), b1);
+        // Edges between the cycles only run one way, so they stay separate
+        graph.connect(a2, (), b1);
+        graph.connect(b3, (), lone);
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![b1, b2, b3], vec![a1, a2], vec![lone]]
+        );
+    }
+
+    #[test]
+    fn test_strongly_connected_long_chain() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        let ids: Vec<GraphNodeId> = (0..10_000).map(|n| graph.insert(n).unwrap()).collect();
+        for pair in ids.windows(2) {
+            graph.connect(pair[0], (), pair[1]);
+        }
+        graph.connect(ids[9_999], (), ids[0]);
+
+        let components = graph.strongly_connected_components();
+
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), 10_000);
+    }
+
+    #[test]
+    fn test_strongly_connected_empty_graph() {
+        let graph: Graph<i32, ()> = Graph::new();
+        assert!(graph.strongly_connected_components().is_empty());
+    }
+}
@@ -37,6 +37,92 @@ impl<T, E> Graph<T, E> {
     pub fn edge_count(&self) -> usize {
         self.edges.len()
     }
+
+    /// Returns the number of edges leaving a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node to count outgoing edges for
+    ///
+    /// # Returns
+    ///
+    /// The number of edges whose source is `id`, or `0` if the node doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// assert_eq!(graph.out_degree(n1), 1);
+    /// assert_eq!(graph.out_degree(n2), 0);
+    /// ```
+    pub fn out_degree(&self, id: GraphNodeId) -> usize {
+        self.edges.values().filter(|edge| edge.from == id).count()
+    }
+
+    /// Returns the number of edges arriving at a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node to count incoming edges for
+    ///
+    /// # Returns
+    ///
+    /// The number of edges whose destination is `id`, or `0` if the node doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// assert_eq!(graph.in_degree(n1), 0);
+    /// assert_eq!(graph.in_degree(n2), 1);
+    /// ```
+    pub fn in_degree(&self, id: GraphNodeId) -> usize {
+        self.edges.values().filter(|edge| edge.to == id).count()
+    }
+
+    /// Returns both the incoming and outgoing edge counts of a node.
+    ///
+    /// This walks the edges once, which is cheaper than calling
+    /// [`Graph::in_degree`] and [`Graph::out_degree`] separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node to count edges for
+    ///
+    /// # Returns
+    ///
+    /// A tuple of `(in_degree, out_degree)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let n3 = graph.insert(3).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// graph.connect(n2, (), n3);
+    /// assert_eq!(graph.degree(n2), (1, 1));
+    /// ```
+    pub fn degree(&self, id: GraphNodeId) -> (usize, usize) {
+        self.edges
+            .values()
+            .fold((0, 0), |(incoming, outgoing), edge| {
+                (
+                    incoming + usize::from(edge.to == id),
+                    outgoing + usize::from(edge.from == id),
+                )
+            })
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +164,71 @@ mod tests {
             "Invalid edge should not affect count"
         );
     }
+
+    #[test]
+    fn test_degree_isolated_node() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let isolated = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n2, (), n3);
+
+        assert_eq!(graph.in_degree(isolated), 0);
+        assert_eq!(graph.out_degree(isolated), 0);
+        assert_eq!(graph.degree(isolated), (0, 0));
+        assert_eq!(
+            graph.degree(GraphNodeId::new(999)),
+            (0, 0),
+            "Missing nodes should have no edges"
+        );
+    }
+
+    #[test]
+    fn test_degree_incoming_only() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let sink = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, (), sink);
+        graph.connect(n2, (), sink);
+
+        assert_eq!(graph.in_degree(sink), 2);
+        assert_eq!(graph.out_degree(sink), 0);
+        assert_eq!(graph.degree(sink), (2, 0));
+    }
+
+    #[test]
+    fn test_degree_outgoing_only() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let source = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        graph.connect(source, (), n1);
+        graph.connect(source, (), n1);
+
+        assert_eq!(graph.in_degree(source), 0);
+        assert_eq!(
+            graph.out_degree(source),
+            2,
+            "Parallel edges should each be counted"
+        );
+        assert_eq!(graph.degree(source), (0, 2));
+    }
+
+    #[test]
+    fn test_degree_star_center() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let center = graph.insert(0).unwrap();
+        for i in 1..=3 {
+            let leaf = graph.insert(i).unwrap();
+            graph.connect(center, (), leaf);
+        }
+        for i in 4..=5 {
+            let leaf = graph.insert(i).unwrap();
+            graph.connect(leaf, (), center);
+        }
+
+        assert_eq!(graph.out_degree(center), 3);
+        assert_eq!(graph.in_degree(center), 2);
+        assert_eq!(graph.degree(center), (2, 3));
+    }
 }
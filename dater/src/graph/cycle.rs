@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use super::*;
+use crate::{LinearData, Stack};
+
+/// The visiting state of a node during cycle detection.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    /// The node is on the current DFS path
+    Gray,
+    /// The node and all of its descendants have been fully explored
+    Black,
+}
+
+impl<T, E> Graph<T, E> {
+    /// Checks whether the graph contains a directed cycle.
+    ///
+    /// This is the same as [`Graph::has_cycle_iterative`], so it is safe to call
+    /// on graphs with very long paths.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one cycle exists, `false` if the graph is acyclic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// assert!(!graph.has_cycle());
+    ///
+    /// graph.connect(n2, (), n1);
+    /// assert!(graph.has_cycle());
+    /// ```
+    pub fn has_cycle(&self) -> bool {
+        self.has_cycle_iterative()
+    }
+
+    /// Checks whether the graph contains a directed cycle, without recursion.
+    ///
+    /// Uses a three-color depth-first search: a node is gray while it is on the
+    /// current search path and black once all of its descendants are explored.
+    /// Reaching a gray node again means a cycle has been found. Every node is used
+    /// as a starting point, so cycles in disconnected components are also found.
+    ///
+    /// The call stack is simulated with a [`Stack`] of `(node, entering)` pairs.
+    /// A node is pushed with `true` to be entered and colored gray, and again with
+    /// `false` underneath its successors, so that it is colored black once they
+    /// have all been explored. Memory use is bounded by the number of edges rather
+    /// than the thread's stack size, so chains of any length are handled.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one cycle exists, `false` if the graph is acyclic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let n3 = graph.insert(3).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// graph.connect(n2, (), n3);
+    /// assert!(!graph.has_cycle_iterative());
+    ///
+    /// graph.connect(n3, (), n1);
+    /// assert!(graph.has_cycle_iterative());
+    /// ```
+    pub fn has_cycle_iterative(&self) -> bool {
+        let adjacency = self.adjacency();
+        let mut colors: HashMap<GraphNodeId, Color> = HashMap::new();
+        let mut pending: Stack<(GraphNodeId, bool)> = Stack::new();
+
+        for &start in self.nodes.keys() {
+            if colors.contains_key(&start) {
+                continue;
+            }
+
+            pending.push((start, true));
+            while let Some((id, entering)) = pending.pop() {
+                if !entering {
+                    colors.insert(id, Color::Black);
+                    continue;
+                }
+                // A node can be queued by several parents before it is entered
+                if colors.contains_key(&id) {
+                    continue;
+                }
+
+                colors.insert(id, Color::Gray);
+                pending.push((id, false));
+                for &next in &adjacency[&id] {
+                    match colors.get(&next) {
+                        Some(Color::Gray) => return true,
+                        Some(Color::Black) => {}
+                        None => pending.push((next, true)),
+                    }
+                }
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_graph_has_no_cycle() {
+        let graph: Graph<i32, ()> = Graph::new();
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn test_single_node_has_no_cycle() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        graph.insert(1);
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn test_two_nodes_connected_both_ways() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n2, (), n1);
+
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn test_five_node_cycle() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let nodes: Vec<GraphNodeId> = (0..5).map(|i| graph.insert(i).unwrap()).collect();
+        for pair in nodes.windows(2) {
+            graph.connect(pair[0], (), pair[1]);
+        }
+        assert!(!graph.has_cycle(), "A chain should not be a cycle");
+
+        graph.connect(nodes[4], (), nodes[0]);
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn test_diamond_is_not_a_cycle() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let top = graph.insert(0).unwrap();
+        let left = graph.insert(1).unwrap();
+        let right = graph.insert(2).unwrap();
+        let bottom = graph.insert(3).unwrap();
+        graph.connect(top, (), left);
+        graph.connect(top, (), right);
+        graph.connect(left, (), bottom);
+        graph.connect(right, (), bottom);
+
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn test_cycle_in_disconnected_component() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let n4 = graph.insert(4).unwrap();
+        let n5 = graph.insert(5).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n3, (), n4);
+        graph.connect(n4, (), n5);
+        graph.connect(n5, (), n3);
+
+        assert!(graph.has_cycle());
+    }
+
+    fn chain(length: usize) -> (Graph<usize, ()>, Vec<GraphNodeId>) {
+        let mut graph = Graph::new();
+        let ids: Vec<GraphNodeId> = (0..length).map(|n| graph.insert(n).unwrap()).collect();
+        for pair in ids.windows(2) {
+            graph.connect(pair[0], (), pair[1]);
+        }
+        (graph, ids)
+    }
+
+    #[test]
+    fn test_long_chain_has_no_cycle() {
+        let (graph, _) = chain(10_000);
+
+        assert!(!graph.has_cycle_iterative());
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn test_long_chain_with_back_edge() {
+        let (mut graph, ids) = chain(10_000);
+        graph.connect(ids[9_999], (), ids[5_000]);
+
+        assert!(graph.has_cycle_iterative());
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn test_iterative_shared_descendants() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let a = graph.insert(0).unwrap();
+        let b = graph.insert(1).unwrap();
+        let c = graph.insert(2).unwrap();
+        let d = graph.insert(3).unwrap();
+        graph.connect(a, (), b);
+        graph.connect(a, (), c);
+        graph.connect(c, (), b);
+        graph.connect(b, (), d);
+        graph.connect(c, (), d);
+
+        assert!(
+            !graph.has_cycle_iterative(),
+            "Reconverging paths are not a cycle"
+        );
+
+        graph.connect(d, (), c);
+        assert!(graph.has_cycle_iterative());
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::{self, Write};
+
+use super::*;
+
+impl<T: fmt::Display, E: fmt::Display> Graph<T, E> {
+    /// Renders the graph in the Graphviz DOT language.
+    ///
+    /// Each node is declared as `n<id>` labelled with its value, and each edge is
+    /// labelled with its value. Quotes and backslashes in labels are escaped, so
+    /// the output can be passed straight to `dot -Tpng`. Nodes and edges are
+    /// written in ID order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, i32> = Graph::new();
+    /// let a = graph.insert("a").unwrap();
+    /// let b = graph.insert("b").unwrap();
+    /// graph.connect(a, 5, b);
+    ///
+    /// assert_eq!(
+    ///     graph.to_dot(),
+    ///     "digraph {\n    n0 [label=\"a\"];\n    n1 [label=\"b\"];\n    n0 -> n1 [label=\"5\"];\n}\n"
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut nodes: Vec<&GraphNode<T>> = self.nodes.values().collect();
+        nodes.sort_by_key(|node| node.id);
+        let mut edges: Vec<&GraphEdge<E>> = self.edges.values().collect();
+        edges.sort_by_key(|edge| edge.id);
+
+        let mut dot = String::from("digraph {\n");
+        for node in nodes {
+            // Writing to a String never fails
+            let _ = writeln!(
+                dot,
+                "    n{} [label=\"{}\"];",
+                node.id.index(),
+                escape_label(&**node)
+            );
+        }
+        for edge in edges {
+            let _ = writeln!(
+                dot,
+                "    n{} -> n{} [label=\"{}\"];",
+                edge.from.index(),
+                edge.to.index(),
+                escape_label(&**edge)
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Formats a value for use inside a double-quoted DOT string.
+fn escape_label<V: fmt::Display>(value: &V) -> String {
+    value
+        .to_string()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_dot_declarations() {
+        let mut graph: Graph<&str, f64> = Graph::new();
+        let a = graph.insert("start").unwrap();
+        let b = graph.insert("middle").unwrap();
+        let c = graph.insert("end").unwrap();
+        graph.connect(a, 1.5, b);
+        graph.connect(b, 2.0, c);
+
+        let dot = graph.to_dot();
+        let lines: Vec<&str> = dot.lines().map(str::trim).collect();
+
+        assert_eq!(lines.first(), Some(&"digraph {"));
+        assert_eq!(lines.last(), Some(&"}"));
+        assert!(lines.contains(&"n0 [label=\"start\"];"));
+        assert!(lines.contains(&"n1 [label=\"middle\"];"));
+        assert!(lines.contains(&"n2 [label=\"end\"];"));
+        assert!(lines.contains(&"n0 -> n1 [label=\"1.5\"];"));
+        assert!(lines.contains(&"n1 -> n2 [label=\"2\"];"));
+        assert_eq!(lines.len(), 7, "Expected 3 nodes, 2 edges and the braces");
+    }
+
+    #[test]
+    fn test_to_dot_escapes_labels() {
+        let mut graph: Graph<String, String> = Graph::new();
+        let a = graph.insert(String::from("say \"hi\"")).unwrap();
+        let b = graph.insert(String::from("back\\slash")).unwrap();
+        graph.connect(a, String::from("two\nlines"), b);
+
+        let dot = graph.to_dot();
+
+        assert!(dot.contains("n0 [label=\"say \\\"hi\\\"\"];"));
+        assert!(dot.contains("n1 [label=\"back\\\\slash\"];"));
+        assert!(dot.contains("n0 -> n1 [label=\"two\\nlines\"];"));
+    }
+
+    #[test]
+    fn test_to_dot_empty_graph() {
+        let graph: Graph<i32, i32> = Graph::new();
+        assert_eq!(graph.to_dot(), "digraph {\n}\n");
+    }
+}
@@ -83,6 +83,27 @@ impl<E> GraphEdge<E> {
     pub fn to(&self) -> GraphNodeId {
         self.to
     }
+
+    /// Returns a mutable reference to the edge's value.
+    ///
+    /// The edge's ID and endpoints are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::{GraphEdge, GraphEdgeId, GraphNodeId};
+    /// let mut edge = GraphEdge::new(GraphEdgeId::new(0), GraphNodeId::new(1), GraphNodeId::new(2), 1.0);
+    /// *edge.value_mut() *= 2.0;
+    /// assert_eq!(*edge, 2.0);
+    /// ```
+    pub fn value_mut(&mut self) -> &mut E {
+        &mut self.value
+    }
+
+    /// Consumes the edge and returns its value.
+    pub(crate) fn into_value(self) -> E {
+        *self.value
+    }
 }
 
 /// Implements Deref to allow direct access to the edge's value.
@@ -138,11 +159,27 @@ mod tests {
             GraphEdgeId::new(0),
             GraphNodeId::new(0),
             GraphNodeId::new(1),
-            3.14_f64,
+            2.5_f64,
         );
         assert!(
-            (edge_float.value.as_ref() - 3.14_f64).abs() < f64::EPSILON,
+            (edge_float.value.as_ref() - 2.5_f64).abs() < f64::EPSILON,
             "Should store and compare f64 values"
         );
     }
+
+    #[test]
+    fn test_edge_value_mut() {
+        let mut edge = GraphEdge::new(
+            GraphEdgeId::new(1),
+            GraphNodeId::new(2),
+            GraphNodeId::new(3),
+            1.5_f64,
+        );
+        *edge.value_mut() = 4.0;
+
+        assert_eq!(*edge, 4.0);
+        assert_eq!(edge.id(), GraphEdgeId::new(1));
+        assert_eq!(edge.from(), GraphNodeId::new(2));
+        assert_eq!(edge.to(), GraphNodeId::new(3));
+    }
 }
@@ -1,8 +1,15 @@
-#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphEdgeId(usize);
 
 impl GraphEdgeId {
     pub fn new(id: usize) -> Self {
         GraphEdgeId(id)
     }
+
+    /// Returns the raw numeric value of this ID.
+    #[cfg(feature = "serde")]
+    pub(crate) fn index(self) -> usize {
+        self.0
+    }
 }
@@ -0,0 +1,158 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::*;
+
+impl<T> Graph<T, f64> {
+    /// Computes the maximum flow from `source` to `sink`.
+    ///
+    /// Edge values are treated as capacities. Parallel edges between the same pair
+    /// of nodes are combined by summing their capacities, and negative capacities
+    /// are treated as zero. The flow is computed with the Edmonds-Karp algorithm,
+    /// which repeatedly augments along the shortest path in the residual graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The ID of the node the flow leaves from
+    /// * `sink` - The ID of the node the flow arrives at
+    ///
+    /// # Returns
+    ///
+    /// The value of the maximum flow, or `0.0` if `source == sink` or either node
+    /// doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, f64> = Graph::new();
+    /// let s = graph.insert("s").unwrap();
+    /// let a = graph.insert("a").unwrap();
+    /// let t = graph.insert("t").unwrap();
+    /// graph.connect(s, 3.0, a);
+    /// graph.connect(a, 2.0, t);
+    ///
+    /// assert_eq!(graph.max_flow(s, t), 2.0);
+    /// ```
+    pub fn max_flow(&self, source: GraphNodeId, sink: GraphNodeId) -> f64 {
+        if source == sink || !self.nodes.contains_key(&source) || !self.nodes.contains_key(&sink) {
+            return 0.0;
+        }
+
+        // Residual capacities, with a zero-capacity reverse entry for every edge
+        let mut residual: HashMap<(GraphNodeId, GraphNodeId), f64> = HashMap::new();
+        let mut adjacency: HashMap<GraphNodeId, Vec<GraphNodeId>> = HashMap::new();
+        for edge in self.edges.values() {
+            if !residual.contains_key(&(edge.from, edge.to))
+                && !residual.contains_key(&(edge.to, edge.from))
+            {
+                adjacency.entry(edge.from).or_default().push(edge.to);
+                adjacency.entry(edge.to).or_default().push(edge.from);
+            }
+            *residual.entry((edge.from, edge.to)).or_insert(0.0) += (**edge).max(0.0);
+            residual.entry((edge.to, edge.from)).or_insert(0.0);
+        }
+
+        let mut total = 0.0;
+        loop {
+            // Breadth-first search for the shortest augmenting path
+            let mut previous: HashMap<GraphNodeId, GraphNodeId> = HashMap::new();
+            let mut frontier = VecDeque::from([source]);
+            while let Some(current) = frontier.pop_front() {
+                if current == sink {
+                    break;
+                }
+                for &next in adjacency.get(&current).into_iter().flatten() {
+                    if next != source
+                        && !previous.contains_key(&next)
+                        && residual[&(current, next)] > 0.0
+                    {
+                        previous.insert(next, current);
+                        frontier.push_back(next);
+                    }
+                }
+            }
+
+            if !previous.contains_key(&sink) {
+                return total;
+            }
+
+            let mut bottleneck = f64::INFINITY;
+            let mut node = sink;
+            while let Some(&prior) = previous.get(&node) {
+                bottleneck = bottleneck.min(residual[&(prior, node)]);
+                node = prior;
+            }
+
+            let mut node = sink;
+            while let Some(&prior) = previous.get(&node) {
+                *residual.get_mut(&(prior, node)).unwrap() -= bottleneck;
+                *residual.get_mut(&(node, prior)).unwrap() += bottleneck;
+                node = prior;
+            }
+
+            total += bottleneck;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_flow_classic_example() {
+        // The network from Cormen et al., Introduction to Algorithms, figure 26.1
+        let mut graph: Graph<&str, f64> = Graph::new();
+        let s = graph.insert("s").unwrap();
+        let v1 = graph.insert("v1").unwrap();
+        let v2 = graph.insert("v2").unwrap();
+        let v3 = graph.insert("v3").unwrap();
+        let v4 = graph.insert("v4").unwrap();
+        let t = graph.insert("t").unwrap();
+        graph.connect(s, 16.0, v1);
+        graph.connect(s, 13.0, v2);
+        graph.connect(v1, 10.0, v2);
+        graph.connect(v2, 4.0, v1);
+        graph.connect(v1, 12.0, v3);
+        graph.connect(v3, 9.0, v2);
+        graph.connect(v2, 14.0, v4);
+        graph.connect(v4, 7.0, v3);
+        graph.connect(v3, 20.0, t);
+        graph.connect(v4, 4.0, t);
+
+        assert_eq!(graph.max_flow(s, t), 23.0);
+    }
+
+    #[test]
+    fn test_max_flow_parallel_edges() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let s = graph.insert(0).unwrap();
+        let t = graph.insert(1).unwrap();
+        graph.connect(s, 2.0, t);
+        graph.connect(s, 3.5, t);
+
+        assert_eq!(graph.max_flow(s, t), 5.5);
+    }
+
+    #[test]
+    fn test_max_flow_invalid_endpoints() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let s = graph.insert(0).unwrap();
+        let t = graph.insert(1).unwrap();
+        graph.connect(s, 2.0, t);
+
+        assert_eq!(graph.max_flow(s, s), 0.0, "Source equal to sink");
+        assert_eq!(graph.max_flow(s, GraphNodeId::new(999)), 0.0);
+        assert_eq!(graph.max_flow(GraphNodeId::new(999), t), 0.0);
+    }
+
+    #[test]
+    fn test_max_flow_unreachable_sink() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let s = graph.insert(0).unwrap();
+        let t = graph.insert(1).unwrap();
+        graph.connect(t, 2.0, s);
+
+        assert_eq!(graph.max_flow(s, t), 0.0);
+    }
+}
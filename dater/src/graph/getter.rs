@@ -50,6 +50,252 @@ impl<T, E> Graph<T, E> {
     pub fn edge(&self, id: GraphEdgeId) -> Option<&GraphEdge<E>> {
         self.edges.get(&id)
     }
+
+    /// Retrieves a mutable reference to a node by its ID.
+    ///
+    /// Use [`GraphNode::value_mut`] to update the node's value in place. The
+    /// node keeps its ID and all of its edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut GraphNode<T>)` - A mutable reference to the node if it exists
+    /// * `None` - If no node exists with the given ID
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let node_id = graph.insert(42).unwrap();
+    /// *graph.node_mut(node_id).unwrap().value_mut() = 7;
+    /// assert_eq!(**graph.node(node_id).unwrap(), 7);
+    /// ```
+    pub fn node_mut(&mut self, id: GraphNodeId) -> Option<&mut GraphNode<T>> {
+        self.nodes.get_mut(&id)
+    }
+
+    /// Retrieves a mutable reference to an edge by its ID.
+    ///
+    /// Use [`GraphEdge::value_mut`] to update the edge's value in place. The
+    /// edge keeps its ID and endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the edge to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut GraphEdge<E>)` - A mutable reference to the edge if it exists
+    /// * `None` - If no edge exists with the given ID
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, f64> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let edge_id = graph.connect(n1, 1.0, n2).unwrap();
+    /// *graph.edge_mut(edge_id).unwrap().value_mut() += 0.5;
+    /// assert_eq!(**graph.edge(edge_id).unwrap(), 1.5);
+    /// ```
+    pub fn edge_mut(&mut self, id: GraphEdgeId) -> Option<&mut GraphEdge<E>> {
+        self.edges.get_mut(&id)
+    }
+
+    /// Returns an iterator over every node in the graph.
+    ///
+    /// The iteration order is unspecified and may differ between calls or after
+    /// the graph is modified. Sort by [`GraphNode::id`] if a stable order is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// graph.insert(1);
+    /// graph.insert(2);
+    ///
+    /// let total: i32 = graph.nodes().map(|node| **node).sum();
+    /// assert_eq!(total, 3);
+    /// ```
+    pub fn nodes(&self) -> impl Iterator<Item = &GraphNode<T>> {
+        self.nodes.values()
+    }
+
+    /// Returns an iterator over every edge in the graph.
+    ///
+    /// The iteration order is unspecified and may differ between calls or after
+    /// the graph is modified. Sort by [`GraphEdge::id`] if a stable order is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, f64> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, 1.5, n2);
+    /// graph.connect(n2, 2.0, n1);
+    ///
+    /// let total: f64 = graph.edges().map(|edge| **edge).sum();
+    /// assert_eq!(total, 3.5);
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = &GraphEdge<E>> {
+        self.edges.values()
+    }
+
+    /// Returns an iterator over mutable references to every node in the graph.
+    ///
+    /// The iteration order is unspecified, as with [`Graph::nodes`].
+    pub fn nodes_mut(&mut self) -> impl Iterator<Item = &mut GraphNode<T>> {
+        self.nodes.values_mut()
+    }
+
+    /// Returns an iterator over mutable references to every edge in the graph.
+    ///
+    /// The iteration order is unspecified, as with [`Graph::edges`].
+    pub fn edges_mut(&mut self) -> impl Iterator<Item = &mut GraphEdge<E>> {
+        self.edges.values_mut()
+    }
+
+    /// Returns the edges leaving a node.
+    ///
+    /// Unlike [`Graph::neighbors`], this yields the edges themselves, so their
+    /// values are available alongside the destination IDs. The order of the
+    /// results is unspecified.
+    ///
+    /// The iterator borrows the graph, so the graph can't be modified while it
+    /// is alive:
+    ///
+    /// ```compile_fail
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, f64> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let mut edges = graph.edges_from(n1);
+    /// graph.insert(2);
+    /// edges.next();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node whose outgoing edges to return
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the outgoing edges, which is empty if the node doesn't
+    /// exist or has no outgoing edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, f64> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, 2.5, n2);
+    ///
+    /// let weights: Vec<f64> = graph.edges_from(n1).map(|edge| **edge).collect();
+    /// assert_eq!(weights, vec![2.5]);
+    /// ```
+    pub fn edges_from(&self, id: GraphNodeId) -> impl Iterator<Item = &GraphEdge<E>> {
+        self.edges.values().filter(move |edge| edge.from == id)
+    }
+
+    /// Returns the edges arriving at a node.
+    ///
+    /// Unlike [`Graph::predecessors`], this yields the edges themselves, so their
+    /// values are available alongside the source IDs. The order of the results
+    /// is unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node whose incoming edges to return
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the incoming edges, which is empty if the node doesn't
+    /// exist or has no incoming edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, &str> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, "label", n2);
+    ///
+    /// let labels: Vec<&str> = graph.edges_to(n2).map(|edge| **edge).collect();
+    /// assert_eq!(labels, vec!["label"]);
+    /// ```
+    pub fn edges_to(&self, id: GraphNodeId) -> impl Iterator<Item = &GraphEdge<E>> {
+        self.edges.values().filter(move |edge| edge.to == id)
+    }
+
+    /// Returns the IDs of the nodes that a node has outgoing edges to.
+    ///
+    /// A node connected by several edges appears once per edge. The order of the
+    /// results is unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node whose successors to return
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the destination node IDs, which is empty if the node
+    /// doesn't exist or has no outgoing edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, (), n2);
+    ///
+    /// assert_eq!(graph.neighbors(n1).collect::<Vec<_>>(), vec![n2]);
+    /// assert_eq!(graph.neighbors(n2).count(), 0);
+    /// ```
+    pub fn neighbors(&self, id: GraphNodeId) -> impl Iterator<Item = GraphNodeId> + '_ {
+        self.edges_from(id).map(|edge| edge.to)
+    }
+
+    /// Returns the IDs of the nodes that have edges pointing to a node.
+    ///
+    /// A node connected by several edges appears once per edge. The order of the
+    /// results is unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node whose predecessors to return
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the source node IDs, which is empty if the node doesn't
+    /// exist or has no incoming edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, (), n2);
+    ///
+    /// assert_eq!(graph.predecessors(n2).collect::<Vec<_>>(), vec![n1]);
+    /// assert_eq!(graph.predecessors(n1).count(), 0);
+    /// ```
+    pub fn predecessors(&self, id: GraphNodeId) -> impl Iterator<Item = GraphNodeId> + '_ {
+        self.edges_to(id).map(|edge| edge.from)
+    }
 }
 
 #[cfg(test)]
@@ -82,4 +328,156 @@ mod tests {
         let invalid_id = GraphEdgeId::new(999);
         assert!(graph.edge(invalid_id).is_none());
     }
+
+    #[test]
+    fn test_neighbors_multiple_outgoing_edges() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let center = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(center, (), n1);
+        graph.connect(center, (), n2);
+        graph.connect(n3, (), center);
+
+        let mut neighbors: Vec<GraphNodeId> = graph.neighbors(center).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![n1, n2]);
+
+        let predecessors: Vec<GraphNodeId> = graph.predecessors(center).collect();
+        assert_eq!(predecessors, vec![n3]);
+    }
+
+    #[test]
+    fn test_neighbors_without_edges() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let isolated = graph.insert(0).unwrap();
+
+        assert_eq!(graph.neighbors(isolated).count(), 0);
+        assert_eq!(graph.predecessors(isolated).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors_missing_node() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, (), n2);
+
+        let invalid_id = GraphNodeId::new(999);
+        assert_eq!(graph.neighbors(invalid_id).count(), 0);
+        assert_eq!(graph.predecessors(invalid_id).count(), 0);
+    }
+
+    #[test]
+    fn test_edges_from_and_to_expose_values() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n1, 1.5, n2);
+        graph.connect(n1, 2.5, n3);
+        graph.connect(n3, 4.0, n2);
+
+        let mut outgoing: Vec<(GraphNodeId, f64)> =
+            graph.edges_from(n1).map(|edge| (edge.to, **edge)).collect();
+        outgoing.sort_by_key(|(id, _)| *id);
+        assert_eq!(outgoing, vec![(n2, 1.5), (n3, 2.5)]);
+
+        let incoming: f64 = graph.edges_to(n2).map(|edge| **edge).sum();
+        assert_eq!(incoming, 5.5, "Both edges into n2 should be visited");
+    }
+
+    #[test]
+    fn test_edges_from_and_to_isolated_node() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let isolated = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, 1.0, n2);
+
+        assert_eq!(graph.edges_from(isolated).count(), 0);
+        assert_eq!(graph.edges_to(isolated).count(), 0);
+        assert_eq!(graph.edges_from(GraphNodeId::new(999)).count(), 0);
+    }
+
+    #[test]
+    fn test_nodes_and_edges_iterators() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let e1 = graph.connect(n1, 1.0, n2).unwrap();
+        let e2 = graph.connect(n2, 2.0, n3).unwrap();
+
+        let mut node_ids: Vec<GraphNodeId> = graph.nodes().map(|node| node.id()).collect();
+        node_ids.sort();
+        assert_eq!(node_ids, vec![n1, n2, n3]);
+
+        let mut edge_ids: Vec<GraphEdgeId> = graph.edges().map(|edge| edge.id()).collect();
+        edge_ids.sort();
+        assert_eq!(edge_ids, vec![e1, e2]);
+    }
+
+    #[test]
+    fn test_nodes_and_edges_iterators_empty_graph() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+
+        assert_eq!(graph.nodes().count(), 0);
+        assert_eq!(graph.edges().count(), 0);
+        assert_eq!(graph.nodes_mut().count(), 0);
+        assert_eq!(graph.edges_mut().count(), 0);
+    }
+
+    #[test]
+    fn test_node_mut_preserves_id_and_edges() {
+        let mut graph: Graph<String, ()> = Graph::new();
+        let n1 = graph.insert(String::from("a")).unwrap();
+        let n2 = graph.insert(String::from("b")).unwrap();
+        let edge_id = graph.connect(n1, (), n2).unwrap();
+
+        let node = graph.node_mut(n1).unwrap();
+        node.value_mut().push_str("bc");
+        assert_eq!(node.id(), n1, "Mutating should not change the node ID");
+
+        assert_eq!(**graph.node(n1).unwrap(), "abc");
+        assert_eq!(graph.neighbors(n1).collect::<Vec<_>>(), vec![n2]);
+        assert_eq!(graph.edge(edge_id).unwrap().from(), n1);
+        assert!(graph.node_mut(GraphNodeId::new(999)).is_none());
+    }
+
+    #[test]
+    fn test_edge_mut_preserves_endpoints() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let edge_id = graph.connect(n1, 1.0, n2).unwrap();
+
+        *graph.edge_mut(edge_id).unwrap().value_mut() = 3.0;
+
+        let edge = graph.edge(edge_id).unwrap();
+        assert_eq!(**edge, 3.0);
+        assert_eq!(edge.from(), n1);
+        assert_eq!(edge.to(), n2);
+        assert!(graph.edge_mut(GraphEdgeId::new(999)).is_none());
+    }
+
+    #[test]
+    fn test_mutable_iterators_visit_everything() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, 1.0, n2);
+
+        for node in graph.nodes_mut() {
+            *node.value_mut() *= 10;
+        }
+        for edge in graph.edges_mut() {
+            *edge.value_mut() += 0.5;
+        }
+
+        assert_eq!(**graph.node(n1).unwrap(), 10);
+        assert_eq!(**graph.node(n2).unwrap(), 20);
+        assert_eq!(graph.edges().map(|edge| **edge).sum::<f64>(), 1.5);
+    }
 }
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Combines two graphs into one containing every node and edge from both.
+    ///
+    /// This is a consuming form of [`Graph::extend`]: the IDs from `self` are kept,
+    /// and the nodes and edges of `other` are given new IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The graph whose nodes and edges are added
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut a: Graph<i32, ()> = Graph::new();
+    /// let a1 = a.insert(1).unwrap();
+    /// let a2 = a.insert(2).unwrap();
+    /// a.connect(a1, (), a2);
+    ///
+    /// let mut b: Graph<i32, ()> = Graph::new();
+    /// b.insert(3);
+    ///
+    /// let merged = a.merge(b);
+    /// assert_eq!(merged.node_count(), 3);
+    /// assert_eq!(merged.edge_count(), 1);
+    /// ```
+    pub fn merge(mut self, other: Graph<T, E>) -> Graph<T, E> {
+        self.extend(other);
+        self
+    }
+
+    /// Moves every node and edge from another graph into this one.
+    ///
+    /// Nodes and edges from `other` receive fresh IDs in this graph, so they can
+    /// never collide with existing ones. Edges from `other` are reconnected to the
+    /// remapped IDs of their endpoints. The relative order of the IDs from `other`
+    /// is kept, so the first node of `other` gets the lowest new ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The graph whose nodes and edges are added
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut a: Graph<&str, ()> = Graph::new();
+    /// a.insert("a");
+    ///
+    /// let mut b: Graph<&str, ()> = Graph::new();
+    /// let b1 = b.insert("b1").unwrap();
+    /// let b2 = b.insert("b2").unwrap();
+    /// b.connect(b1, (), b2);
+    ///
+    /// a.extend(b);
+    /// assert_eq!(a.node_count(), 3);
+    /// let edge = a.edges().next().unwrap();
+    /// assert_eq!(**a.node(edge.from()).unwrap(), "b1");
+    /// assert_eq!(**a.node(edge.to()).unwrap(), "b2");
+    /// ```
+    pub fn extend(&mut self, other: Graph<T, E>) {
+        let mut nodes: Vec<GraphNode<T>> = other.nodes.into_values().collect();
+        nodes.sort_by_key(|node| node.id);
+        let mut edges: Vec<GraphEdge<E>> = other.edges.into_values().collect();
+        edges.sort_by_key(|edge| edge.id);
+
+        let mut remapped: HashMap<GraphNodeId, GraphNodeId> = HashMap::new();
+        for node in nodes {
+            let old_id = node.id;
+            let id = self.create_node_id();
+            self.nodes.insert(id, GraphNode::new(id, node.into_value()));
+            remapped.insert(old_id, id);
+        }
+
+        for edge in edges {
+            let (from, to) = (remapped[&edge.from], remapped[&edge.to]);
+            let id = self.create_edge_id();
+            self.edges
+                .insert(id, GraphEdge::new(id, from, to, edge.into_value()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(values: &[i32]) -> Graph<i32, i32> {
+        let mut graph = Graph::new();
+        let ids: Vec<GraphNodeId> = values.iter().map(|&v| graph.insert(v).unwrap()).collect();
+        for pair in ids.windows(2) {
+            let weight = **graph.node(pair[0]).unwrap() * 10;
+            graph.connect(pair[0], weight, pair[1]);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_merge_counts() {
+        let a = chain(&[1, 2, 3]);
+        let b = chain(&[4, 5]);
+        let (nodes, edges) = (
+            a.node_count() + b.node_count(),
+            a.edge_count() + b.edge_count(),
+        );
+
+        let merged = a.merge(b);
+
+        assert_eq!(merged.node_count(), nodes);
+        assert_eq!(merged.edge_count(), edges);
+    }
+
+    #[test]
+    fn test_extend_remaps_edges() {
+        let mut a = chain(&[1, 2, 3]);
+        let original: Vec<GraphNodeId> = a.nodes().map(|node| node.id()).collect();
+        let b = chain(&[4, 5, 6]);
+
+        a.extend(b);
+
+        for edge in a.edges() {
+            let from = **a.node(edge.from()).unwrap();
+            let to = **a.node(edge.to()).unwrap();
+            assert_eq!(to, from + 1, "Edges should connect the same values");
+            assert_eq!(**edge, from * 10, "Edge values should be carried over");
+        }
+        for id in original {
+            assert!(a.node(id).is_some(), "IDs in self should be kept");
+        }
+    }
+
+    #[test]
+    fn test_extend_avoids_id_collisions() {
+        let mut a = chain(&[1, 2]);
+        let b = chain(&[3, 4]);
+
+        a.extend(b);
+        let inserted = a.insert(5).unwrap();
+
+        let mut ids: Vec<GraphNodeId> = a.nodes().map(|node| node.id()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 5);
+        assert_eq!(**a.node(inserted).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_extend_with_empty_graph() {
+        let mut a = chain(&[1, 2]);
+        a.extend(Graph::new());
+        assert_eq!(a.node_count(), 2);
+        assert_eq!(a.edge_count(), 1);
+
+        let merged = Graph::new().merge(a);
+        assert_eq!(merged.node_count(), 2);
+        assert_eq!(merged.edge_count(), 1);
+    }
+}
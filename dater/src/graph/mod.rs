@@ -13,10 +13,25 @@ pub use node_id::*;
 mod edge_id;
 pub use edge_id::*;
 
+mod bipartite;
+mod components;
 mod constructor;
 mod counts;
+mod cycle;
+mod display;
+mod flow;
 mod getter;
 mod insert;
+mod merge;
+mod path;
+mod remove;
+mod search;
+#[cfg(feature = "serde")]
+mod serialize;
+mod subgraph;
+mod topological;
+mod transpose;
+mod traversal;
 
 /// A generic graph implementation supporting typed nodes and edges.
 ///
@@ -70,6 +85,26 @@ impl<T, E> Graph<T, E> {
         self.next_edge_id += 1;
         id
     }
+
+    /// Builds a map from every node to the targets of its outgoing edges.
+    ///
+    /// This is an internal helper for algorithms that visit each node's successors
+    /// many times, where scanning all edges per visit would be too slow. Every node
+    /// has an entry, and successors are listed in edge creation order.
+    fn adjacency(&self) -> HashMap<GraphNodeId, Vec<GraphNodeId>> {
+        let mut adjacency: HashMap<GraphNodeId, Vec<GraphNodeId>> =
+            self.nodes.keys().map(|&id| (id, Vec::new())).collect();
+
+        let mut edges: Vec<&GraphEdge<E>> = self.edges.values().collect();
+        edges.sort_by_key(|edge| edge.id);
+        for edge in edges {
+            if let Some(successors) = adjacency.get_mut(&edge.from) {
+                successors.push(edge.to);
+            }
+        }
+
+        adjacency
+    }
 }
 
 // Module-level tests
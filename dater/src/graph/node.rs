@@ -61,6 +61,27 @@ impl<T> GraphNode<T> {
     pub fn id(&self) -> GraphNodeId {
         self.id
     }
+
+    /// Returns a mutable reference to the node's value.
+    ///
+    /// The node's ID and any edges connected to it are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::{GraphNode, GraphNodeId};
+    /// let mut node = GraphNode::new(GraphNodeId::new(0), 1);
+    /// *node.value_mut() += 1;
+    /// assert_eq!(*node, 2);
+    /// ```
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Consumes the node and returns its value.
+    pub(crate) fn into_value(self) -> T {
+        *self.value
+    }
 }
 
 /// Implements Deref to allow direct access to the node's value.
@@ -98,4 +119,14 @@ mod tests {
             "Should be able to call String methods directly"
         );
     }
+
+    #[test]
+    fn test_node_value_mut() {
+        let id = GraphNodeId::new(7);
+        let mut node = GraphNode::new(id, String::from("test"));
+        node.value_mut().push_str("ing");
+
+        assert_eq!(*node, "testing");
+        assert_eq!(node.id(), id, "Mutating the value should not change the ID");
+    }
 }
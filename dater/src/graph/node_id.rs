@@ -1,8 +1,14 @@
-#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphNodeId(usize);
 
 impl GraphNodeId {
     pub fn new(id: usize) -> Self {
         GraphNodeId(id)
     }
+
+    /// Returns the raw numeric value of this ID.
+    pub(crate) fn index(self) -> usize {
+        self.0
+    }
 }
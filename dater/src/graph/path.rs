@@ -0,0 +1,637 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Finds the path with the fewest edges between two nodes.
+    ///
+    /// The search is a breadth-first traversal following edge directions, so the
+    /// returned path has the minimum number of hops. Edge values are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The ID of the node the path starts at
+    /// * `to` - The ID of the node the path ends at
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<GraphNodeId>)` - The node IDs along the path, including both endpoints
+    /// * `None` - If either node doesn't exist or `to` is not reachable from `from`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let n3 = graph.insert(3).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// graph.connect(n2, (), n3);
+    ///
+    /// assert_eq!(graph.find_path(n1, n3), Some(vec![n1, n2, n3]));
+    /// assert_eq!(graph.find_path(n3, n1), None);
+    /// ```
+    pub fn find_path(&self, from: GraphNodeId, to: GraphNodeId) -> Option<Vec<GraphNodeId>> {
+        if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
+            return None;
+        }
+
+        let mut previous: HashMap<GraphNodeId, GraphNodeId> = HashMap::new();
+        let mut frontier = VecDeque::from([from]);
+
+        while let Some(current) = frontier.pop_front() {
+            if current == to {
+                let mut path = vec![to];
+                let mut node = to;
+                while let Some(&prior) = previous.get(&node) {
+                    path.push(prior);
+                    node = prior;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for next in self.neighbors(current) {
+                if next != from && !previous.contains_key(&next) {
+                    previous.insert(next, current);
+                    frontier.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Checks whether `to` can be reached from `from` by following edge directions.
+    ///
+    /// This is a breadth-first search that stops as soon as `to` is discovered and
+    /// doesn't record the path, so it is cheaper than [`Graph::find_path`] when
+    /// only reachability matters. A node always has a path to itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The ID of the node to start from
+    /// * `to` - The ID of the node to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if a directed path exists, `false` if it doesn't or either node is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let n3 = graph.insert(3).unwrap();
+    /// graph.connect(n1, (), n2);
+    /// graph.connect(n2, (), n3);
+    ///
+    /// assert!(graph.has_path(n1, n3));
+    /// assert!(!graph.has_path(n3, n1));
+    /// ```
+    pub fn has_path(&self, from: GraphNodeId, to: GraphNodeId) -> bool {
+        if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
+            return false;
+        }
+        if from == to {
+            return true;
+        }
+
+        let adjacency = self.adjacency();
+        let mut visited: HashSet<GraphNodeId> = HashSet::from([from]);
+        let mut frontier = VecDeque::from([from]);
+
+        while let Some(current) = frontier.pop_front() {
+            for &next in &adjacency[&current] {
+                if next == to {
+                    return true;
+                }
+                if visited.insert(next) {
+                    frontier.push_back(next);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Finds every simple path between two nodes, up to a maximum length.
+    ///
+    /// A simple path follows edge directions and never visits a node twice. The
+    /// search is a depth-first traversal that tracks the nodes on the current path
+    /// and stops extending a path once it has `max_depth` edges, which bounds the
+    /// work on dense graphs where the number of paths grows combinatorially.
+    /// Parallel edges between the same pair of nodes don't produce duplicate paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The ID of the node every path starts at
+    /// * `to` - The ID of the node every path ends at
+    /// * `max_depth` - The largest number of edges a returned path may have
+    ///
+    /// # Returns
+    ///
+    /// The node IDs along each path, including both endpoints. Paths are ordered
+    /// depth-first, following successors in the order their edges were created.
+    /// If `from == to` the only path is `[from]`. The result is empty if either
+    /// node doesn't exist or no path fits within `max_depth`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let a = graph.insert(1).unwrap();
+    /// let b = graph.insert(2).unwrap();
+    /// let c = graph.insert(3).unwrap();
+    /// graph.connect(a, (), b);
+    /// graph.connect(b, (), c);
+    /// graph.connect(a, (), c);
+    ///
+    /// assert_eq!(graph.all_simple_paths(a, c, 5), vec![vec![a, b, c], vec![a, c]]);
+    /// assert_eq!(graph.all_simple_paths(a, c, 1), vec![vec![a, c]]);
+    /// ```
+    pub fn all_simple_paths(
+        &self,
+        from: GraphNodeId,
+        to: GraphNodeId,
+        max_depth: usize,
+    ) -> Vec<Vec<GraphNodeId>> {
+        if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
+            return Vec::new();
+        }
+        if from == to {
+            return vec![vec![from]];
+        }
+
+        let mut adjacency = self.adjacency();
+        for successors in adjacency.values_mut() {
+            let mut seen = HashSet::new();
+            successors.retain(|id| seen.insert(*id));
+        }
+
+        let mut paths = Vec::new();
+        let mut path = vec![from];
+        let mut on_path: HashSet<GraphNodeId> = HashSet::from([from]);
+        // The index of the next successor to try for each node on the path
+        let mut next_index = vec![0];
+
+        while let Some(&current) = path.last() {
+            let index = next_index.last_mut().unwrap();
+            let next = adjacency[&current].get(*index).copied();
+            *index += 1;
+
+            match next {
+                Some(next) if next == to => {
+                    if path.len() <= max_depth {
+                        let mut found = path.clone();
+                        found.push(to);
+                        paths.push(found);
+                    }
+                }
+                Some(next) if path.len() < max_depth && !on_path.contains(&next) => {
+                    on_path.insert(next);
+                    path.push(next);
+                    next_index.push(0);
+                }
+                Some(_) => {}
+                None => {
+                    on_path.remove(&current);
+                    path.pop();
+                    next_index.pop();
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+/// An entry in the Dijkstra priority queue, ordered so the lowest cost pops first.
+struct Frontier {
+    cost: f64,
+    node: GraphNodeId,
+}
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl<T, E: Clone + Into<f64>> Graph<T, E> {
+    /// Finds the lowest-cost path between two nodes using Dijkstra's algorithm.
+    ///
+    /// Edge values are converted to `f64` and used as the cost of following the
+    /// edge. Negative weights are not supported: Dijkstra's algorithm assumes
+    /// costs never decrease along a path. If the search reaches an edge whose
+    /// cost is negative or NaN, it stops and returns `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The ID of the node the path starts at
+    /// * `to` - The ID of the node the path ends at
+    ///
+    /// # Returns
+    ///
+    /// * `Some((f64, Vec<GraphNodeId>))` - The total cost and the node IDs along the
+    ///   path, including both endpoints
+    /// * `None` - If either node doesn't exist, `to` is not reachable from `from`,
+    ///   or a negative or NaN edge cost was reached during the search
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, f64> = Graph::new();
+    /// let a = graph.insert("a").unwrap();
+    /// let b = graph.insert("b").unwrap();
+    /// let c = graph.insert("c").unwrap();
+    /// graph.connect(a, 10.0, c);
+    /// graph.connect(a, 2.0, b);
+    /// graph.connect(b, 3.0, c);
+    ///
+    /// assert_eq!(graph.shortest_path(a, c), Some((5.0, vec![a, b, c])));
+    /// ```
+    pub fn shortest_path(
+        &self,
+        from: GraphNodeId,
+        to: GraphNodeId,
+    ) -> Option<(f64, Vec<GraphNodeId>)> {
+        if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
+            return None;
+        }
+
+        let mut costs: HashMap<GraphNodeId, f64> = HashMap::from([(from, 0.0)]);
+        let mut previous: HashMap<GraphNodeId, GraphNodeId> = HashMap::new();
+        let mut heap = BinaryHeap::from([Frontier {
+            cost: 0.0,
+            node: from,
+        }]);
+
+        while let Some(Frontier { cost, node }) = heap.pop() {
+            if node == to {
+                let mut path = vec![to];
+                let mut current = to;
+                while let Some(&prior) = previous.get(&current) {
+                    path.push(prior);
+                    current = prior;
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+
+            // Skip stale entries superseded by a cheaper route
+            if cost > costs[&node] {
+                continue;
+            }
+
+            for edge in self.edges_from(node) {
+                let weight: f64 = (**edge).clone().into();
+                if weight.is_nan() || weight < 0.0 {
+                    return None;
+                }
+
+                let next_cost = cost + weight;
+                let improves = match costs.get(&edge.to) {
+                    Some(&known) => next_cost < known,
+                    None => true,
+                };
+                if improves {
+                    costs.insert(edge.to, next_cost);
+                    previous.insert(edge.to, node);
+                    heap.push(Frontier {
+                        cost: next_cost,
+                        node: edge.to,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_path_multiple_hops() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let n4 = graph.insert(4).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n2, (), n3);
+        graph.connect(n3, (), n4);
+
+        let path = graph.find_path(n1, n4).unwrap();
+        assert_eq!(path, vec![n1, n2, n3, n4]);
+        assert_eq!(path.first(), Some(&n1), "Path should start at the source");
+        assert_eq!(path.last(), Some(&n4), "Path should end at the target");
+    }
+
+    #[test]
+    fn test_find_path_prefers_fewest_hops() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let n4 = graph.insert(4).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n2, (), n3);
+        graph.connect(n3, (), n4);
+        graph.connect(n1, (), n4);
+
+        assert_eq!(graph.find_path(n1, n4), Some(vec![n1, n4]));
+    }
+
+    #[test]
+    fn test_find_path_to_self() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+
+        assert_eq!(graph.find_path(n1, n1), Some(vec![n1]));
+    }
+
+    #[test]
+    fn test_find_path_disconnected() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n1, (), n2);
+
+        assert!(graph.find_path(n1, n3).is_none());
+        assert!(
+            graph.find_path(n2, n1).is_none(),
+            "Edges should only be followed in their direction"
+        );
+        assert!(graph.find_path(n1, GraphNodeId::new(999)).is_none());
+    }
+
+    #[test]
+    fn test_has_path_direct_edge() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, (), n2);
+
+        assert!(graph.has_path(n1, n2));
+        assert!(graph.has_path(n1, n1), "A node should reach itself");
+    }
+
+    #[test]
+    fn test_has_path_multiple_hops() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let nodes: Vec<GraphNodeId> = (0..5).map(|i| graph.insert(i).unwrap()).collect();
+        for pair in nodes.windows(2) {
+            graph.connect(pair[0], (), pair[1]);
+        }
+
+        assert!(graph.has_path(nodes[0], nodes[4]));
+        assert!(graph.has_path(nodes[1], nodes[3]));
+    }
+
+    #[test]
+    fn test_has_path_reverse_direction() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n2, (), n3);
+
+        assert!(
+            !graph.has_path(n3, n1),
+            "Edges should only be followed in their direction"
+        );
+    }
+
+    #[test]
+    fn test_has_path_disconnected() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let n4 = graph.insert(4).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n3, (), n4);
+
+        assert!(!graph.has_path(n1, n4));
+        assert!(!graph.has_path(n1, GraphNodeId::new(999)));
+        assert!(!graph.has_path(GraphNodeId::new(999), GraphNodeId::new(999)));
+    }
+
+    #[test]
+    fn test_shortest_path_triangle() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n1, 1.0, n2);
+        graph.connect(n2, 2.0, n3);
+        graph.connect(n1, 4.0, n3);
+
+        assert_eq!(graph.shortest_path(n1, n3), Some((3.0, vec![n1, n2, n3])));
+        assert_eq!(graph.shortest_path(n1, n2), Some((1.0, vec![n1, n2])));
+        assert_eq!(graph.shortest_path(n1, n1), Some((0.0, vec![n1])));
+    }
+
+    #[test]
+    fn test_shortest_path_avoids_expensive_direct_edge() {
+        let mut graph: Graph<&str, f64> = Graph::new();
+        let a = graph.insert("a").unwrap();
+        let b = graph.insert("b").unwrap();
+        let c = graph.insert("c").unwrap();
+        let d = graph.insert("d").unwrap();
+        let e = graph.insert("e").unwrap();
+        graph.connect(a, 100.0, e);
+        graph.connect(a, 1.0, b);
+        graph.connect(b, 1.0, c);
+        graph.connect(c, 1.0, d);
+        graph.connect(d, 1.0, e);
+
+        let (cost, path) = graph.shortest_path(a, e).unwrap();
+        assert_eq!(cost, 4.0);
+        assert_eq!(
+            path,
+            vec![a, b, c, d, e],
+            "The longer route should win on cost"
+        );
+    }
+
+    #[test]
+    fn test_shortest_path_disconnected() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n1, 1.0, n2);
+
+        assert!(graph.shortest_path(n1, n3).is_none());
+        assert!(graph.shortest_path(n2, n1).is_none());
+        assert!(graph.shortest_path(n1, GraphNodeId::new(999)).is_none());
+    }
+
+    #[test]
+    fn test_shortest_path_with_integer_weights() {
+        let mut graph: Graph<i32, u32> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, 7, n2);
+
+        assert_eq!(graph.shortest_path(n1, n2), Some((7.0, vec![n1, n2])));
+    }
+
+    fn assert_simple(path: &[GraphNodeId]) {
+        let unique: HashSet<&GraphNodeId> = path.iter().collect();
+        assert_eq!(unique.len(), path.len(), "Path {:?} repeats a node", path);
+    }
+
+    #[test]
+    fn test_all_simple_paths_multiple_routes() {
+        let mut graph: Graph<&str, ()> = Graph::new();
+        let a = graph.insert("a").unwrap();
+        let b = graph.insert("b").unwrap();
+        let c = graph.insert("c").unwrap();
+        let d = graph.insert("d").unwrap();
+        graph.connect(a, (), b);
+        graph.connect(a, (), c);
+        graph.connect(b, (), c);
+        graph.connect(c, (), b);
+        graph.connect(b, (), d);
+        graph.connect(c, (), d);
+        // A cycle back to the start must not be followed
+        graph.connect(d, (), a);
+
+        let paths = graph.all_simple_paths(a, d, 10);
+
+        assert_eq!(
+            paths,
+            vec![
+                vec![a, b, c, d],
+                vec![a, b, d],
+                vec![a, c, b, d],
+                vec![a, c, d],
+            ]
+        );
+        for path in &paths {
+            assert_simple(path);
+        }
+    }
+
+    #[test]
+    fn test_all_simple_paths_max_depth() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let a = graph.insert(1).unwrap();
+        let b = graph.insert(2).unwrap();
+        let c = graph.insert(3).unwrap();
+        graph.connect(a, (), b);
+        graph.connect(b, (), c);
+        graph.connect(a, (), c);
+
+        assert_eq!(graph.all_simple_paths(a, c, 2).len(), 2);
+        assert_eq!(graph.all_simple_paths(a, c, 1), vec![vec![a, c]]);
+        assert!(graph.all_simple_paths(a, c, 0).is_empty());
+    }
+
+    #[test]
+    fn test_all_simple_paths_dense_graph() {
+        let mut graph: Graph<usize, ()> = Graph::new();
+        let ids: Vec<GraphNodeId> = (0..6).map(|n| graph.insert(n).unwrap()).collect();
+        for &from in &ids {
+            for &to in &ids {
+                if from != to {
+                    graph.connect(from, (), to);
+                }
+            }
+        }
+
+        let paths = graph.all_simple_paths(ids[0], ids[5], 6);
+
+        // Every ordered selection of the four middle nodes: 1 + 4 + 12 + 24 + 24
+        assert_eq!(paths.len(), 65);
+        for path in &paths {
+            assert_simple(path);
+            assert_eq!(path.first(), Some(&ids[0]));
+            assert_eq!(path.last(), Some(&ids[5]));
+        }
+    }
+
+    #[test]
+    fn test_all_simple_paths_edge_cases() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let a = graph.insert(1).unwrap();
+        let b = graph.insert(2).unwrap();
+        graph.connect(a, (), b);
+        graph.connect(a, (), b);
+
+        assert_eq!(graph.all_simple_paths(a, b, 3), vec![vec![a, b]]);
+        assert_eq!(graph.all_simple_paths(a, a, 3), vec![vec![a]]);
+        assert!(graph.all_simple_paths(b, a, 3).is_empty());
+        assert!(graph
+            .all_simple_paths(a, GraphNodeId::new(999), 3)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_shortest_path_negative_cycle() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let a = graph.insert(1).unwrap();
+        let b = graph.insert(2).unwrap();
+        let c = graph.insert(3).unwrap();
+        let d = graph.insert(4).unwrap();
+        graph.connect(a, 1.0, b);
+        graph.connect(b, 1.0, c);
+        graph.connect(c, -5.0, b);
+        graph.connect(c, 1.0, d);
+
+        assert_eq!(graph.shortest_path(a, d), None);
+    }
+
+    #[test]
+    fn test_shortest_path_nan_weight() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let a = graph.insert(1).unwrap();
+        let b = graph.insert(2).unwrap();
+        graph.connect(a, f64::NAN, b);
+
+        assert_eq!(graph.shortest_path(a, b), None);
+    }
+
+    #[test]
+    fn test_shortest_path_ignores_unreached_negative_edge() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let a = graph.insert(1).unwrap();
+        let b = graph.insert(2).unwrap();
+        let c = graph.insert(3).unwrap();
+        graph.connect(a, 2.0, b);
+        graph.connect(c, -1.0, a);
+
+        assert_eq!(graph.shortest_path(a, b), Some((2.0, vec![a, b])));
+    }
+}
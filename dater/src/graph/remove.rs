@@ -0,0 +1,146 @@
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Removes a node and every edge connected to it from the graph.
+    ///
+    /// Both incoming and outgoing edges of the node are removed, so no edge is
+    /// left referencing a node that no longer exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the node to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The value of the removed node
+    /// * `None` - If no node exists with the given ID
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// graph.connect(n1, (), n2);
+    ///
+    /// assert_eq!(graph.remove_node(n1), Some(1));
+    /// assert_eq!(graph.node_count(), 1);
+    /// assert_eq!(graph.edge_count(), 0);
+    /// ```
+    pub fn remove_node(&mut self, id: GraphNodeId) -> Option<T> {
+        let node = self.nodes.remove(&id)?;
+        self.edges
+            .retain(|_, edge| edge.from != id && edge.to != id);
+
+        Some(node.into_value())
+    }
+
+    /// Removes an edge from the graph.
+    ///
+    /// The nodes connected by the edge are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the edge to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Some(E)` - The value of the removed edge
+    /// * `None` - If no edge exists with the given ID
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, &str> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let edge = graph.connect(n1, "depends on", n2).unwrap();
+    ///
+    /// assert_eq!(graph.remove_edge(edge), Some("depends on"));
+    /// assert_eq!(graph.edge_count(), 0);
+    /// assert_eq!(graph.node_count(), 2);
+    /// ```
+    pub fn remove_edge(&mut self, id: GraphEdgeId) -> Option<E> {
+        self.edges.remove(&id).map(GraphEdge::into_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_node_returns_value() {
+        let mut graph: Graph<String, ()> = Graph::new();
+        let node = graph.insert(String::from("test")).unwrap();
+
+        assert_eq!(graph.remove_node(node), Some(String::from("test")));
+        assert_eq!(graph.node_count(), 0);
+        assert!(graph.node(node).is_none());
+    }
+
+    #[test]
+    fn test_remove_node_cleans_up_edges() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let incoming = graph.connect(n1, (), n2).unwrap();
+        let outgoing = graph.connect(n2, (), n3).unwrap();
+        let unrelated = graph.connect(n1, (), n3).unwrap();
+
+        graph.remove_node(n2);
+
+        assert_eq!(graph.edge_count(), 1, "Both edges of n2 should be removed");
+        assert!(graph.edge(incoming).is_none());
+        assert!(graph.edge(outgoing).is_none());
+        assert!(graph.edge(unrelated).is_some());
+    }
+
+    #[test]
+    fn test_remove_missing_node() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let node = graph.insert(1).unwrap();
+
+        assert_eq!(graph.remove_node(GraphNodeId::new(999)), None);
+        assert_eq!(graph.remove_node(node), Some(1));
+        assert_eq!(
+            graph.remove_node(node),
+            None,
+            "Node can only be removed once"
+        );
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let removed = graph.connect(n1, 1.5, n2).unwrap();
+        let kept = graph.connect(n2, 2.5, n1).unwrap();
+
+        assert_eq!(graph.remove_edge(removed), Some(1.5));
+        assert_eq!(graph.edge_count(), 1);
+        assert!(graph.edge(removed).is_none());
+        assert!(graph.edge(kept).is_some());
+        assert_eq!(graph.node_count(), 2, "Nodes should not be affected");
+    }
+
+    #[test]
+    fn test_remove_missing_edge() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let edge = graph.connect(n1, 1.5, n2).unwrap();
+
+        assert_eq!(graph.remove_edge(GraphEdgeId::new(999)), None);
+        assert_eq!(graph.remove_edge(edge), Some(1.5));
+        assert_eq!(
+            graph.remove_edge(edge),
+            None,
+            "Edge can only be removed once"
+        );
+    }
+}
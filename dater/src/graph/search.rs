@@ -0,0 +1,212 @@
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Finds a node whose value satisfies a predicate.
+    ///
+    /// If several nodes match, the one with the smallest ID is returned, which is
+    /// the one inserted first.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A function deciding whether a node value matches
+    ///
+    /// # Returns
+    ///
+    /// * `Some(GraphNodeId)` - The ID of the first matching node
+    /// * `None` - If no node matches
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, ()> = Graph::new();
+    /// graph.insert("a");
+    /// let b = graph.insert("b").unwrap();
+    ///
+    /// assert_eq!(graph.find_node(|value| *value == "b"), Some(b));
+    /// assert_eq!(graph.find_node(|value| *value == "c"), None);
+    /// ```
+    pub fn find_node<F>(&self, predicate: F) -> Option<GraphNodeId>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.nodes
+            .values()
+            .filter(|node| predicate(node))
+            .map(|node| node.id)
+            .min()
+    }
+
+    /// Finds every node whose value satisfies a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A function deciding whether a node value matches
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the matching nodes, sorted, which is empty if no node matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// graph.insert(2);
+    /// let n3 = graph.insert(3).unwrap();
+    ///
+    /// assert_eq!(graph.find_nodes(|value| value % 2 == 1), vec![n1, n3]);
+    /// ```
+    pub fn find_nodes<F>(&self, predicate: F) -> Vec<GraphNodeId>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut ids: Vec<GraphNodeId> = self
+            .nodes
+            .values()
+            .filter(|node| predicate(node))
+            .map(|node| node.id)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Finds an edge whose value satisfies a predicate.
+    ///
+    /// If several edges match, the one with the smallest ID is returned, which is
+    /// the one connected first.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A function deciding whether an edge value matches
+    ///
+    /// # Returns
+    ///
+    /// * `Some(GraphEdgeId)` - The ID of the first matching edge
+    /// * `None` - If no edge matches
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, &str> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let road = graph.connect(n1, "road", n2).unwrap();
+    ///
+    /// assert_eq!(graph.find_edge(|value| *value == "road"), Some(road));
+    /// ```
+    pub fn find_edge<F>(&self, predicate: F) -> Option<GraphEdgeId>
+    where
+        F: Fn(&E) -> bool,
+    {
+        self.edges
+            .values()
+            .filter(|edge| predicate(edge))
+            .map(|edge| edge.id)
+            .min()
+    }
+
+    /// Finds every edge whose value satisfies a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A function deciding whether an edge value matches
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the matching edges, sorted, which is empty if no edge matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, f64> = Graph::new();
+    /// let n1 = graph.insert(1).unwrap();
+    /// let n2 = graph.insert(2).unwrap();
+    /// let heavy = graph.connect(n1, 5.0, n2).unwrap();
+    /// graph.connect(n2, 1.0, n1);
+    ///
+    /// assert_eq!(graph.find_edges(|weight| *weight > 2.0), vec![heavy]);
+    /// ```
+    pub fn find_edges<F>(&self, predicate: F) -> Vec<GraphEdgeId>
+    where
+        F: Fn(&E) -> bool,
+    {
+        let mut ids: Vec<GraphEdgeId> = self
+            .edges
+            .values()
+            .filter(|edge| predicate(edge))
+            .map(|edge| edge.id)
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_node_by_value() {
+        let mut graph: Graph<String, ()> = Graph::new();
+        graph.insert(String::from("alpha"));
+        let beta = graph.insert(String::from("beta")).unwrap();
+        graph.insert(String::from("gamma"));
+
+        assert_eq!(graph.find_node(|value| value == "beta"), Some(beta));
+    }
+
+    #[test]
+    fn test_find_node_returns_first_inserted() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        graph.insert(1);
+        let first = graph.insert(2).unwrap();
+        graph.insert(2);
+        graph.insert(2);
+
+        assert_eq!(graph.find_node(|value| *value == 2), Some(first));
+    }
+
+    #[test]
+    fn test_find_nodes_multiple_matches() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let ids: Vec<GraphNodeId> = (0..10).map(|n| graph.insert(n).unwrap()).collect();
+
+        let even = graph.find_nodes(|value| value % 2 == 0);
+
+        assert_eq!(even, vec![ids[0], ids[2], ids[4], ids[6], ids[8]]);
+    }
+
+    #[test]
+    fn test_find_nodes_no_match() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        graph.insert(1);
+        graph.insert(2);
+
+        assert_eq!(graph.find_node(|value| *value > 5), None);
+        assert!(graph.find_nodes(|value| *value > 5).is_empty());
+        assert!(Graph::<i32, ()>::new().find_nodes(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_find_edges() {
+        let mut graph: Graph<i32, &str> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let road1 = graph.connect(n1, "road", n2).unwrap();
+        graph.connect(n2, "rail", n3);
+        let road2 = graph.connect(n3, "road", n1).unwrap();
+
+        assert_eq!(graph.find_edge(|value| *value == "road"), Some(road1));
+        assert_eq!(
+            graph.find_edges(|value| *value == "road"),
+            vec![road1, road2]
+        );
+        assert_eq!(graph.find_edge(|value| *value == "air"), None);
+        assert!(graph.find_edges(|value| *value == "air").is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::*;
+
+/// The serialized form of a node, borrowing its value from the graph.
+#[derive(Serialize)]
+struct NodeRef<'a, T> {
+    id: GraphNodeId,
+    value: &'a T,
+}
+
+/// The serialized form of an edge, borrowing its value from the graph.
+#[derive(Serialize)]
+struct EdgeRef<'a, E> {
+    id: GraphEdgeId,
+    from: GraphNodeId,
+    to: GraphNodeId,
+    value: &'a E,
+}
+
+/// The serialized form of a graph, borrowing from the graph being written.
+#[derive(Serialize)]
+struct GraphRef<'a, T, E> {
+    nodes: Vec<NodeRef<'a, T>>,
+    edges: Vec<EdgeRef<'a, E>>,
+    next_node_id: usize,
+    next_edge_id: usize,
+}
+
+/// The owned form of a node read back from a serialized graph.
+#[derive(Deserialize)]
+struct NodeData<T> {
+    id: GraphNodeId,
+    value: T,
+}
+
+/// The owned form of an edge read back from a serialized graph.
+#[derive(Deserialize)]
+struct EdgeData<E> {
+    id: GraphEdgeId,
+    from: GraphNodeId,
+    to: GraphNodeId,
+    value: E,
+}
+
+/// The owned form of a graph read back from its serialized representation.
+#[derive(Deserialize)]
+struct GraphData<T, E> {
+    nodes: Vec<NodeData<T>>,
+    edges: Vec<EdgeData<E>>,
+    next_node_id: usize,
+    next_edge_id: usize,
+}
+
+/// Serializes the graph as lists of nodes and edges with their IDs.
+///
+/// Nodes and edges are written in ID order so the output is deterministic.
+/// The ID counters are included, so a deserialized graph continues to hand out
+/// the same IDs as the original would have.
+impl<T: Serialize, E: Serialize> Serialize for Graph<T, E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut nodes: Vec<NodeRef<T>> = self
+            .nodes
+            .values()
+            .map(|node| NodeRef {
+                id: node.id,
+                value: &**node,
+            })
+            .collect();
+        nodes.sort_by_key(|node| node.id);
+
+        let mut edges: Vec<EdgeRef<E>> = self
+            .edges
+            .values()
+            .map(|edge| EdgeRef {
+                id: edge.id,
+                from: edge.from,
+                to: edge.to,
+                value: &**edge,
+            })
+            .collect();
+        edges.sort_by_key(|edge| edge.id);
+
+        GraphRef {
+            nodes,
+            edges,
+            next_node_id: self.next_node_id,
+            next_edge_id: self.next_edge_id,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a graph, keeping the node and edge IDs it was written with.
+///
+/// Fails if an ID appears twice, an ID is too large to continue the sequence from,
+/// or an edge is a self-loop or refers to a node that isn't present.
+impl<'de, T: Deserialize<'de>, E: Deserialize<'de>> Deserialize<'de> for Graph<T, E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = GraphData::<T, E>::deserialize(deserializer)?;
+
+        let mut next_node_id = data.next_node_id;
+        let mut nodes = HashMap::with_capacity(data.nodes.len());
+        for node in data.nodes {
+            let after =
+                node.id.index().checked_add(1).ok_or_else(|| {
+                    D::Error::custom(format!("node id {:?} is too large", node.id))
+                })?;
+            next_node_id = next_node_id.max(after);
+            if nodes
+                .insert(node.id, GraphNode::new(node.id, node.value))
+                .is_some()
+            {
+                return Err(D::Error::custom(format!("duplicate node id {:?}", node.id)));
+            }
+        }
+
+        let mut next_edge_id = data.next_edge_id;
+        let mut edges = HashMap::with_capacity(data.edges.len());
+        for edge in data.edges {
+            if !nodes.contains_key(&edge.from) || !nodes.contains_key(&edge.to) {
+                return Err(D::Error::custom(format!(
+                    "edge {:?} refers to a missing node",
+                    edge.id
+                )));
+            }
+            if edge.from == edge.to {
+                return Err(D::Error::custom(format!(
+                    "edge {:?} is a self-loop",
+                    edge.id
+                )));
+            }
+            let after =
+                edge.id.index().checked_add(1).ok_or_else(|| {
+                    D::Error::custom(format!("edge id {:?} is too large", edge.id))
+                })?;
+            next_edge_id = next_edge_id.max(after);
+            let value = GraphEdge::new(edge.id, edge.from, edge.to, edge.value);
+            if edges.insert(edge.id, value).is_some() {
+                return Err(D::Error::custom(format!("duplicate edge id {:?}", edge.id)));
+            }
+        }
+
+        Ok(Graph {
+            nodes,
+            edges,
+            next_node_id,
+            next_edge_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> (Graph<String, f64>, Vec<GraphNodeId>, Vec<GraphEdgeId>) {
+        let mut graph = Graph::new();
+        let nodes: Vec<GraphNodeId> = ["a", "b", "c", "d"]
+            .iter()
+            .map(|value| graph.insert(value.to_string()).unwrap())
+            .collect();
+        let edges = vec![
+            graph.connect(nodes[0], 1.5, nodes[1]).unwrap(),
+            graph.connect(nodes[1], 2.5, nodes[2]).unwrap(),
+            graph.connect(nodes[3], 4.0, nodes[0]).unwrap(),
+        ];
+        (graph, nodes, edges)
+    }
+
+    #[test]
+    fn test_round_trip_preserves_graph() {
+        let (mut graph, nodes, edges) = sample_graph();
+        // Leave a gap in the IDs so remapping would be noticed
+        graph.remove_node(nodes[2]);
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let restored: Graph<String, f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.node_count(), graph.node_count());
+        assert_eq!(restored.edge_count(), graph.edge_count());
+        for node in graph.nodes() {
+            assert_eq!(restored.node(node.id()), Some(node));
+        }
+        for edge in graph.edges() {
+            assert_eq!(restored.edge(edge.id()), Some(edge));
+        }
+        assert_eq!(
+            restored.find_path(nodes[3], nodes[1]),
+            Some(vec![nodes[3], nodes[0], nodes[1]])
+        );
+        assert!(restored.edge(edges[1]).is_none());
+    }
+
+    #[test]
+    fn test_round_trip_continues_id_sequence() {
+        let (graph, nodes, _) = sample_graph();
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let mut restored: Graph<String, f64> = serde_json::from_str(&json).unwrap();
+        let inserted = restored.insert(String::from("e")).unwrap();
+
+        assert!(!nodes.contains(&inserted), "New IDs should not collide");
+    }
+
+    #[test]
+    fn test_ids_serialize_as_numbers() {
+        assert_eq!(serde_json::to_string(&GraphNodeId::new(3)).unwrap(), "3");
+        assert_eq!(serde_json::to_string(&GraphEdgeId::new(4)).unwrap(), "4");
+    }
+
+    #[test]
+    fn test_deserialize_rejects_dangling_edge() {
+        let json = r#"{
+            "nodes": [{"id": 0, "value": 1}],
+            "edges": [{"id": 0, "from": 0, "to": 7, "value": 1.0}],
+            "next_node_id": 1,
+            "next_edge_id": 1
+        }"#;
+
+        let result: Result<Graph<i32, f64>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_duplicate_nodes() {
+        let json = r#"{
+            "nodes": [{"id": 0, "value": 1}, {"id": 0, "value": 2}],
+            "edges": [],
+            "next_node_id": 1,
+            "next_edge_id": 0
+        }"#;
+
+        let result: Result<Graph<i32, f64>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_self_loop() {
+        let json = r#"{
+            "nodes": [{"id": 0, "value": 1}],
+            "edges": [{"id": 0, "from": 0, "to": 0, "value": 1.0}],
+            "next_node_id": 1,
+            "next_edge_id": 1
+        }"#;
+
+        let result: Result<Graph<i32, f64>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_overflowing_ids() {
+        let max = usize::MAX;
+        let node_json = format!(
+            r#"{{"nodes": [{{"id": {}, "value": 1}}], "edges": [], "next_node_id": 0, "next_edge_id": 0}}"#,
+            max
+        );
+        let result: Result<Graph<i32, f64>, _> = serde_json::from_str(&node_json);
+        assert!(result.is_err());
+
+        let edge_json = format!(
+            r#"{{"nodes": [{{"id": 0, "value": 1}}, {{"id": 1, "value": 2}}],
+                "edges": [{{"id": {}, "from": 0, "to": 1, "value": 1.0}}],
+                "next_node_id": 2, "next_edge_id": 0}}"#,
+            max
+        );
+        let result: Result<Graph<i32, f64>, _> = serde_json::from_str(&edge_json);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Creates a new graph containing only the edges that satisfy a predicate.
+    ///
+    /// The resulting graph holds every edge for which `predicate` returns `true`,
+    /// together with the nodes at either end of those edges. Nodes that are not
+    /// connected by any kept edge are dropped. Node and edge IDs are preserved,
+    /// so IDs from this graph remain valid in the induced graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A function deciding whether an edge is kept
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, f64> = Graph::new();
+    /// let a = graph.insert("a").unwrap();
+    /// let b = graph.insert("b").unwrap();
+    /// let c = graph.insert("c").unwrap();
+    /// let heavy = graph.connect(a, 5.0, b).unwrap();
+    /// graph.connect(b, 1.0, c);
+    ///
+    /// let induced = graph.induce_by_edge(|edge| **edge > 2.0);
+    /// assert_eq!(induced.edge_count(), 1);
+    /// assert!(induced.edge(heavy).is_some());
+    /// assert!(induced.node(c).is_none());
+    /// ```
+    pub fn induce_by_edge<F>(&self, predicate: F) -> Graph<T, E>
+    where
+        F: Fn(&GraphEdge<E>) -> bool,
+        T: Clone,
+        E: Clone,
+    {
+        let edges: HashMap<GraphEdgeId, GraphEdge<E>> = self
+            .edges
+            .values()
+            .filter(|edge| predicate(edge))
+            .map(|edge| {
+                let copy = GraphEdge::new(edge.id, edge.from, edge.to, (**edge).clone());
+                (edge.id, copy)
+            })
+            .collect();
+
+        let mut nodes = HashMap::new();
+        for edge in edges.values() {
+            for id in [edge.from, edge.to] {
+                if let Some(node) = self.nodes.get(&id) {
+                    nodes
+                        .entry(id)
+                        .or_insert_with(|| GraphNode::new(id, (**node).clone()));
+                }
+            }
+        }
+
+        Graph {
+            nodes,
+            edges,
+            next_node_id: self.next_node_id,
+            next_edge_id: self.next_edge_id,
+        }
+    }
+
+    /// Creates a new graph containing only the given nodes and the edges between them.
+    ///
+    /// An edge is kept only if both of its endpoints are in `nodes`. IDs that don't
+    /// exist in this graph are ignored. Node and edge IDs are preserved, so IDs
+    /// from this graph remain valid in the subgraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The IDs of the nodes to keep
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, ()> = Graph::new();
+    /// let a = graph.insert("a").unwrap();
+    /// let b = graph.insert("b").unwrap();
+    /// let c = graph.insert("c").unwrap();
+    /// let ab = graph.connect(a, (), b).unwrap();
+    /// graph.connect(b, (), c);
+    ///
+    /// let sub = graph.subgraph(&[a, b]);
+    /// assert_eq!(sub.node_count(), 2);
+    /// assert_eq!(sub.edge_count(), 1);
+    /// assert!(sub.edge(ab).is_some());
+    /// ```
+    pub fn subgraph(&self, nodes: &[GraphNodeId]) -> Graph<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        let kept: HashSet<GraphNodeId> = nodes
+            .iter()
+            .copied()
+            .filter(|id| self.nodes.contains_key(id))
+            .collect();
+
+        let nodes = kept
+            .iter()
+            .map(|&id| (id, GraphNode::new(id, (*self.nodes[&id]).clone())))
+            .collect();
+
+        let edges = self
+            .edges
+            .values()
+            .filter(|edge| kept.contains(&edge.from) && kept.contains(&edge.to))
+            .map(|edge| {
+                let copy = GraphEdge::new(edge.id, edge.from, edge.to, (**edge).clone());
+                (edge.id, copy)
+            })
+            .collect();
+
+        Graph {
+            nodes,
+            edges,
+            next_node_id: self.next_node_id,
+            next_edge_id: self.next_edge_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_induce_by_edge_weight_threshold() {
+        let mut graph: Graph<&str, f64> = Graph::new();
+        let a = graph.insert("a").unwrap();
+        let b = graph.insert("b").unwrap();
+        let c = graph.insert("c").unwrap();
+        let d = graph.insert("d").unwrap();
+        let e = graph.insert("e").unwrap();
+        let ab = graph.connect(a, 10.0, b).unwrap();
+        let bc = graph.connect(b, 7.5, c).unwrap();
+        graph.connect(c, 1.0, d);
+        graph.connect(d, 2.0, e);
+
+        let induced = graph.induce_by_edge(|edge| **edge > 5.0);
+
+        assert_eq!(induced.edge_count(), 2);
+        assert_eq!(**induced.edge(ab).unwrap(), 10.0);
+        assert_eq!(**induced.edge(bc).unwrap(), 7.5);
+
+        assert_eq!(induced.node_count(), 3, "Only endpoints should be kept");
+        assert_eq!(**induced.node(a).unwrap(), "a");
+        assert_eq!(**induced.node(b).unwrap(), "b");
+        assert_eq!(**induced.node(c).unwrap(), "c");
+        assert!(induced.node(d).is_none());
+        assert!(induced.node(e).is_none());
+    }
+
+    #[test]
+    fn test_induce_by_edge_no_matches() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, 1.0, n2);
+
+        let induced = graph.induce_by_edge(|_| false);
+
+        assert_eq!(induced.node_count(), 0);
+        assert_eq!(induced.edge_count(), 0);
+    }
+
+    #[test]
+    fn test_induce_by_edge_new_ids_do_not_collide() {
+        let mut graph: Graph<i32, f64> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.insert(3);
+        graph.connect(n1, 1.0, n2);
+
+        let mut induced = graph.induce_by_edge(|_| true);
+        let inserted = induced.insert(4).unwrap();
+
+        assert_ne!(inserted, n1);
+        assert_ne!(inserted, n2);
+        assert_eq!(induced.node_count(), 3);
+    }
+
+    #[test]
+    fn test_subgraph_keeps_internal_edges_only() {
+        let mut graph: Graph<&str, i32> = Graph::new();
+        let a = graph.insert("a").unwrap();
+        let b = graph.insert("b").unwrap();
+        let c = graph.insert("c").unwrap();
+        let d = graph.insert("d").unwrap();
+        let ab = graph.connect(a, 1, b).unwrap();
+        let ca = graph.connect(c, 2, a).unwrap();
+        let bd = graph.connect(b, 3, d).unwrap();
+        let dc = graph.connect(d, 4, c).unwrap();
+
+        let sub = graph.subgraph(&[a, b, c]);
+
+        assert_eq!(sub.node_count(), 3);
+        assert_eq!(**sub.node(a).unwrap(), "a");
+        assert!(sub.node(d).is_none());
+        assert_eq!(**sub.edge(ab).unwrap(), 1);
+        assert_eq!(**sub.edge(ca).unwrap(), 2);
+        assert!(sub.edge(bd).is_none(), "Edges leaving the set are dropped");
+        assert!(sub.edge(dc).is_none(), "Edges entering the set are dropped");
+    }
+
+    #[test]
+    fn test_subgraph_empty_node_list() {
+        let mut graph: Graph<i32, i32> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, 1, n2);
+
+        let sub = graph.subgraph(&[]);
+
+        assert_eq!(sub.node_count(), 0);
+        assert_eq!(sub.edge_count(), 0);
+    }
+
+    #[test]
+    fn test_subgraph_ignores_unknown_and_duplicate_ids() {
+        let mut graph: Graph<i32, i32> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n1, 1, n2);
+
+        let sub = graph.subgraph(&[n1, n1, GraphNodeId::new(999)]);
+
+        assert_eq!(sub.node_count(), 1);
+        assert_eq!(sub.edge_count(), 0);
+    }
+}
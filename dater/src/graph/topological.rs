@@ -0,0 +1,159 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Orders the nodes so that every edge points from an earlier node to a later one.
+    ///
+    /// Uses Kahn's algorithm: nodes with no remaining incoming edges are emitted
+    /// one at a time, removing their outgoing edges as they go. If nodes are left
+    /// over once no more can be emitted, they are part of a cycle and no ordering
+    /// exists. Ties are broken by node ID, so the result is deterministic.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<GraphNodeId>)` - Every node exactly once, in topological order
+    /// * `None` - If the graph contains a cycle
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, ()> = Graph::new();
+    /// let shirt = graph.insert("shirt").unwrap();
+    /// let tie = graph.insert("tie").unwrap();
+    /// let jacket = graph.insert("jacket").unwrap();
+    /// graph.connect(tie, (), jacket);
+    /// graph.connect(shirt, (), tie);
+    ///
+    /// assert_eq!(graph.topological_sort(), Some(vec![shirt, tie, jacket]));
+    ///
+    /// graph.connect(jacket, (), shirt);
+    /// assert_eq!(graph.topological_sort(), None);
+    /// ```
+    pub fn topological_sort(&self) -> Option<Vec<GraphNodeId>> {
+        let adjacency = self.adjacency();
+
+        let mut in_degrees: HashMap<GraphNodeId, usize> =
+            self.nodes.keys().map(|&id| (id, 0)).collect();
+        for successors in adjacency.values() {
+            for next in successors {
+                *in_degrees.get_mut(next).unwrap() += 1;
+            }
+        }
+
+        let mut ready: Vec<GraphNodeId> = in_degrees
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&id, _)| id)
+            .collect();
+        ready.sort();
+        let mut ready = VecDeque::from(ready);
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(current) = ready.pop_front() {
+            order.push(current);
+            for next in &adjacency[&current] {
+                let degree = in_degrees.get_mut(next).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push_back(*next);
+                }
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that every edge in the graph points forward in the given order.
+    fn assert_valid_order(graph: &Graph<i32, ()>, order: &[GraphNodeId]) {
+        assert_eq!(order.len(), graph.node_count(), "Every node should appear");
+        let position: HashMap<GraphNodeId, usize> =
+            order.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        assert_eq!(position.len(), order.len(), "No node should appear twice");
+        for edge in graph.edges.values() {
+            assert!(
+                position[&edge.from] < position[&edge.to],
+                "Edge {:?} -> {:?} points backwards",
+                edge.from,
+                edge.to
+            );
+        }
+    }
+
+    #[test]
+    fn test_topological_sort_chain() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let nodes: Vec<GraphNodeId> = (0..4).map(|i| graph.insert(i).unwrap()).collect();
+        graph.connect(nodes[2], (), nodes[3]);
+        graph.connect(nodes[1], (), nodes[2]);
+        graph.connect(nodes[0], (), nodes[1]);
+
+        assert_eq!(graph.topological_sort(), Some(nodes));
+    }
+
+    #[test]
+    fn test_topological_sort_diamond() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let bottom = graph.insert(3).unwrap();
+        let left = graph.insert(1).unwrap();
+        let right = graph.insert(2).unwrap();
+        let top = graph.insert(0).unwrap();
+        graph.connect(top, (), left);
+        graph.connect(top, (), right);
+        graph.connect(left, (), bottom);
+        graph.connect(right, (), bottom);
+
+        let order = graph.topological_sort().unwrap();
+        assert_valid_order(&graph, &order);
+        assert_eq!(order.first(), Some(&top));
+        assert_eq!(order.last(), Some(&bottom));
+    }
+
+    #[test]
+    fn test_topological_sort_forest() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let a1 = graph.insert(1).unwrap();
+        let a2 = graph.insert(2).unwrap();
+        let a3 = graph.insert(3).unwrap();
+        let b1 = graph.insert(4).unwrap();
+        let b2 = graph.insert(5).unwrap();
+        graph.insert(6);
+        graph.connect(a1, (), a2);
+        graph.connect(a1, (), a3);
+        graph.connect(b2, (), b1);
+
+        let order = graph.topological_sort().unwrap();
+        assert_valid_order(&graph, &order);
+    }
+
+    #[test]
+    fn test_topological_sort_cycle() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let n4 = graph.insert(4).unwrap();
+        graph.connect(n1, (), n2);
+        graph.connect(n2, (), n3);
+        graph.connect(n3, (), n4);
+        graph.connect(n4, (), n2);
+
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn test_topological_sort_empty() {
+        let graph: Graph<i32, ()> = Graph::new();
+        assert_eq!(graph.topological_sort(), Some(vec![]));
+    }
+}
@@ -0,0 +1,83 @@
+use std::mem;
+
+use super::*;
+
+impl<T, E> Graph<T, E> {
+    /// Reverses the direction of every edge in the graph.
+    ///
+    /// The source and destination of each edge are swapped. Nodes, edge values,
+    /// and all IDs are unchanged, so IDs from the original graph remain valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<&str, ()> = Graph::new();
+    /// let a = graph.insert("a").unwrap();
+    /// let b = graph.insert("b").unwrap();
+    /// let edge = graph.connect(a, (), b).unwrap();
+    ///
+    /// let reversed = graph.transpose();
+    /// assert_eq!(reversed.edge(edge).unwrap().from(), b);
+    /// assert_eq!(reversed.edge(edge).unwrap().to(), a);
+    /// ```
+    pub fn transpose(mut self) -> Graph<T, E> {
+        for edge in self.edges.values_mut() {
+            mem::swap(&mut edge.from, &mut edge.to);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose_reverses_edges() {
+        let mut graph: Graph<&str, i32> = Graph::new();
+        let a = graph.insert("a").unwrap();
+        let b = graph.insert("b").unwrap();
+        let c = graph.insert("c").unwrap();
+        let ab = graph.connect(a, 1, b).unwrap();
+        let bc = graph.connect(b, 2, c).unwrap();
+
+        let reversed = graph.transpose();
+
+        assert_eq!(reversed.node_count(), 3);
+        assert_eq!(reversed.edge_count(), 2);
+        let ba = reversed.edge(ab).unwrap();
+        assert_eq!((ba.from(), ba.to(), **ba), (b, a, 1));
+        let cb = reversed.edge(bc).unwrap();
+        assert_eq!((cb.from(), cb.to(), **cb), (c, b, 2));
+        assert_eq!(reversed.find_path(c, a), Some(vec![c, b, a]));
+        assert_eq!(reversed.find_path(a, c), None);
+    }
+
+    #[test]
+    fn test_transpose_twice_is_identity() {
+        let mut graph: Graph<i32, i32> = Graph::new();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n1, 10, n2);
+        graph.connect(n1, 20, n3);
+        graph.connect(n3, 30, n2);
+
+        let mut original: Vec<(GraphEdgeId, GraphNodeId, GraphNodeId, i32)> = graph
+            .edges()
+            .map(|edge| (edge.id(), edge.from(), edge.to(), **edge))
+            .collect();
+        original.sort();
+
+        let restored = graph.transpose().transpose();
+        let mut edges: Vec<(GraphEdgeId, GraphNodeId, GraphNodeId, i32)> = restored
+            .edges()
+            .map(|edge| (edge.id(), edge.from(), edge.to(), **edge))
+            .collect();
+        edges.sort();
+
+        assert_eq!(edges, original);
+        assert_eq!(restored.node_count(), 3);
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::*;
+use crate::{LinearData, Stack};
+
+impl<T, E> Graph<T, E> {
+    /// Traverses the graph breadth-first from a starting node.
+    ///
+    /// The iterator yields the ID of every node reachable from `start` by following
+    /// edge directions, each exactly once, starting with `start` itself. Nodes are
+    /// visited level by level, and the successors of a node are visited in the order
+    /// their edges were created. The traversal is lazy and only explores as much of
+    /// the graph as is consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The ID of the node to start from
+    ///
+    /// # Returns
+    ///
+    /// An iterator over reachable node IDs, which is empty if `start` doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let root = graph.insert(0).unwrap();
+    /// let left = graph.insert(1).unwrap();
+    /// let right = graph.insert(2).unwrap();
+    /// let leaf = graph.insert(3).unwrap();
+    /// graph.connect(root, (), left);
+    /// graph.connect(root, (), right);
+    /// graph.connect(left, (), leaf);
+    ///
+    /// let order: Vec<_> = graph.bfs(root).collect();
+    /// assert_eq!(order, vec![root, left, right, leaf]);
+    /// ```
+    pub fn bfs(&self, start: GraphNodeId) -> impl Iterator<Item = GraphNodeId> + '_ {
+        let mut visited = HashSet::new();
+        let mut frontier = VecDeque::new();
+        if self.nodes.contains_key(&start) {
+            visited.insert(start);
+            frontier.push_back(start);
+        }
+
+        std::iter::from_fn(move || {
+            let current = frontier.pop_front()?;
+            for next in self.successors(current) {
+                if visited.insert(next) {
+                    frontier.push_back(next);
+                }
+            }
+            Some(current)
+        })
+    }
+
+    /// Traverses the graph depth-first from a starting node.
+    ///
+    /// The iterator yields the ID of every node reachable from `start` by following
+    /// edge directions, each exactly once, in pre-order: a node is yielded before
+    /// any of its descendants. Successors are explored in the order their edges
+    /// were created. The traversal uses an explicit [`Stack`] rather than recursion,
+    /// so deep graphs cannot overflow the call stack.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The ID of the node to start from
+    ///
+    /// # Returns
+    ///
+    /// An iterator over reachable node IDs, which is empty if `start` doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::Graph;
+    /// let mut graph: Graph<i32, ()> = Graph::new();
+    /// let root = graph.insert(0).unwrap();
+    /// let left = graph.insert(1).unwrap();
+    /// let right = graph.insert(2).unwrap();
+    /// let leaf = graph.insert(3).unwrap();
+    /// graph.connect(root, (), left);
+    /// graph.connect(root, (), right);
+    /// graph.connect(left, (), leaf);
+    ///
+    /// let order: Vec<_> = graph.dfs(root).collect();
+    /// assert_eq!(order, vec![root, left, leaf, right]);
+    /// ```
+    pub fn dfs(&self, start: GraphNodeId) -> impl Iterator<Item = GraphNodeId> + '_ {
+        let mut visited = HashSet::new();
+        let mut pending = Stack::new();
+        if self.nodes.contains_key(&start) {
+            pending.push(start);
+        }
+
+        std::iter::from_fn(move || {
+            while let Some(current) = pending.pop() {
+                if !visited.insert(current) {
+                    continue;
+                }
+
+                // Push in reverse so the first successor is explored first
+                for next in self.successors(current).into_iter().rev() {
+                    if !visited.contains(&next) {
+                        pending.push(next);
+                    }
+                }
+                return Some(current);
+            }
+            None
+        })
+    }
+
+    /// Returns the targets of the outgoing edges of a node, in edge creation order.
+    fn successors(&self, id: GraphNodeId) -> Vec<GraphNodeId> {
+        let mut edges: Vec<&GraphEdge<E>> =
+            self.edges.values().filter(|edge| edge.from == id).collect();
+        edges.sort_by_key(|edge| edge.id);
+        edges.into_iter().map(|edge| edge.to).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs_visits_level_by_level() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n0 = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let n4 = graph.insert(4).unwrap();
+        graph.connect(n0, (), n1);
+        graph.connect(n1, (), n3);
+        graph.connect(n0, (), n2);
+        graph.connect(n2, (), n4);
+
+        let order: Vec<GraphNodeId> = graph.bfs(n0).collect();
+
+        assert_eq!(order, vec![n0, n1, n2, n3, n4]);
+    }
+
+    #[test]
+    fn test_bfs_visits_each_node_once() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n0 = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n0, (), n1);
+        graph.connect(n0, (), n2);
+        graph.connect(n1, (), n2);
+        graph.connect(n2, (), n0);
+
+        let order: Vec<GraphNodeId> = graph.bfs(n0).collect();
+
+        assert_eq!(order, vec![n0, n1, n2]);
+    }
+
+    #[test]
+    fn test_bfs_skips_unreachable_nodes() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n0 = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n0, (), n1);
+        graph.connect(n2, (), n0);
+
+        let order: Vec<GraphNodeId> = graph.bfs(n0).collect();
+
+        assert_eq!(order, vec![n0, n1]);
+    }
+
+    #[test]
+    fn test_bfs_missing_start() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        graph.insert(0);
+
+        assert_eq!(graph.bfs(GraphNodeId::new(999)).count(), 0);
+    }
+
+    #[test]
+    fn test_dfs_visits_in_pre_order() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n0 = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        let n4 = graph.insert(4).unwrap();
+        graph.connect(n0, (), n1);
+        graph.connect(n1, (), n3);
+        graph.connect(n0, (), n2);
+        graph.connect(n2, (), n4);
+
+        let order: Vec<GraphNodeId> = graph.dfs(n0).collect();
+
+        assert_eq!(order, vec![n0, n1, n3, n2, n4]);
+    }
+
+    #[test]
+    fn test_dfs_visits_each_node_once() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n0 = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n0, (), n1);
+        graph.connect(n0, (), n2);
+        graph.connect(n1, (), n2);
+        graph.connect(n2, (), n0);
+
+        let order: Vec<GraphNodeId> = graph.dfs(n0).collect();
+
+        assert_eq!(order, vec![n0, n1, n2]);
+    }
+
+    #[test]
+    fn test_dfs_differs_from_bfs() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n0 = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        let n3 = graph.insert(3).unwrap();
+        graph.connect(n0, (), n1);
+        graph.connect(n0, (), n2);
+        graph.connect(n1, (), n3);
+
+        let depth_first: Vec<GraphNodeId> = graph.dfs(n0).collect();
+        let breadth_first: Vec<GraphNodeId> = graph.bfs(n0).collect();
+
+        assert_eq!(depth_first, vec![n0, n1, n3, n2]);
+        assert_eq!(breadth_first, vec![n0, n1, n2, n3]);
+    }
+
+    #[test]
+    fn test_dfs_skips_unreachable_nodes() {
+        let mut graph: Graph<i32, ()> = Graph::new();
+        let n0 = graph.insert(0).unwrap();
+        let n1 = graph.insert(1).unwrap();
+        let n2 = graph.insert(2).unwrap();
+        graph.connect(n0, (), n1);
+        graph.connect(n2, (), n0);
+
+        let order: Vec<GraphNodeId> = graph.dfs(n0).collect();
+
+        assert_eq!(order, vec![n0, n1]);
+        assert_eq!(graph.dfs(GraphNodeId::new(999)).count(), 0);
+    }
+}
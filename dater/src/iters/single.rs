@@ -62,6 +62,68 @@ pub trait SingleIterator: Iterator {
             first
         }
     }
+
+    /// Returns all the elements of the iterator if there are exactly `n` of them.
+    ///
+    /// This method consumes the iterator, but stops reading as soon as it finds more
+    /// than `n` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dater::SingleIterator;
+    ///
+    /// assert_eq!(vec![1, 2].into_iter().exactly(2), Some(vec![1, 2]));
+    /// assert_eq!(vec![1, 2, 3].into_iter().exactly(2), None);
+    /// assert_eq!(vec![1].into_iter().exactly(2), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - Returns `Some(items)` if the iterator contains exactly `n` elements
+    /// - Returns `None` if the iterator contains fewer or more than `n` elements
+    fn exactly(mut self, n: usize) -> Option<Vec<Self::Item>>
+    where
+        Self: Sized,
+    {
+        let items = self.at_least(n)?;
+        if self.next().is_some() {
+            None
+        } else {
+            Some(items)
+        }
+    }
+
+    /// Returns the first `n` elements of the iterator if there are at least `n` of them.
+    ///
+    /// Only the first `n` elements are read, so any remaining elements can still be
+    /// taken from the iterator afterwards. If there are fewer than `n` elements, the
+    /// ones that were read are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dater::SingleIterator;
+    ///
+    /// let mut iter = vec![1, 2, 3].into_iter();
+    /// assert_eq!(iter.at_least(2), Some(vec![1, 2]));
+    /// assert_eq!(iter.next(), Some(3));
+    ///
+    /// assert_eq!(vec![1].into_iter().at_least(2), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - Returns `Some(items)` with the first `n` elements if there are at least `n`
+    /// - Returns `None` if the iterator contains fewer than `n` elements
+    fn at_least(&mut self, n: usize) -> Option<Vec<Self::Item>> {
+        let items: Vec<Self::Item> = self.take(n).collect();
+        if items.len() == n {
+            Some(items)
+        } else {
+            None
+        }
+    }
 }
 
 impl<T: ?Sized> SingleIterator for T where T: Iterator {}
@@ -96,7 +158,44 @@ mod tests {
 
     #[test]
     fn test_single_with_reference_iterator() {
-        let vec = vec![1, 2, 3];
-        assert_eq!(vec.iter().filter(|&&x| x == 2).single(), Some(&2));
+        let items = [1, 2, 3];
+        assert_eq!(items.iter().filter(|&&x| x == 2).single(), Some(&2));
+    }
+
+    #[test]
+    fn test_exactly_boundaries() {
+        let items = |count: i32| (0..count).collect::<Vec<_>>().into_iter();
+
+        assert_eq!(items(0).exactly(2), None);
+        assert_eq!(items(1).exactly(2), None);
+        assert_eq!(items(2).exactly(2), Some(vec![0, 1]));
+        assert_eq!(items(3).exactly(2), None);
+    }
+
+    #[test]
+    fn test_exactly_zero() {
+        assert_eq!(Vec::<i32>::new().into_iter().exactly(0), Some(vec![]));
+        assert_eq!(vec![1].into_iter().exactly(0), None);
+    }
+
+    #[test]
+    fn test_at_least_boundaries() {
+        let items = |count: i32| (0..count).collect::<Vec<_>>().into_iter();
+
+        assert_eq!(items(0).at_least(2), None);
+        assert_eq!(items(1).at_least(2), None);
+        assert_eq!(items(2).at_least(2), Some(vec![0, 1]));
+        assert_eq!(items(3).at_least(2), Some(vec![0, 1]));
+        assert_eq!(items(0).at_least(0), Some(vec![]));
+    }
+
+    #[test]
+    fn test_at_least_leaves_rest_unconsumed() {
+        let mut iter = vec![1, 2, 3, 4].into_iter();
+
+        assert_eq!(iter.at_least(1), Some(vec![1]));
+        assert_eq!(iter.at_least(2), Some(vec![2, 3]));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
     }
 }
@@ -3,7 +3,11 @@ use std::{collections::HashMap, fmt, ops::Index};
 /// Represents different types of values that can be stored in a property collection.
 /// This enum provides type safety while allowing flexibility in the types of data
 /// that can be managed within the collection.
+///
+/// With the `serde` feature enabled, values serialize as externally tagged enums
+/// (e.g. `{"Integer": 42}`) so their type survives a round trip.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PropertyValue {
     /// String values for text-based properties
     String(String),
@@ -46,7 +50,12 @@ impl fmt::Display for PropertyValue {
 ///
 /// assert!(matches!(props.get("name"), Some(PropertyValue::String(_))));
 /// ```
-#[derive(Default, Debug)]
+///
+/// With the `serde` feature enabled, a collection serializes as a plain map from
+/// keys to values.
+#[derive(Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct PropertyCollection {
     props: HashMap<String, PropertyValue>,
 }
@@ -84,6 +93,159 @@ impl PropertyCollection {
         self.props.get(key)
     }
 
+    /// Retrieves a string property.
+    ///
+    /// Returns `None` if the key doesn't exist or the value isn't a `PropertyValue::String`.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The identifier of the property to retrieve
+    pub fn get_string(&self, key: &str) -> Option<&str> {
+        match self.props.get(key) {
+            Some(PropertyValue::String(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Retrieves an integer property.
+    ///
+    /// Returns `None` if the key doesn't exist or the value isn't a `PropertyValue::Integer`.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The identifier of the property to retrieve
+    pub fn get_integer(&self, key: &str) -> Option<i64> {
+        match self.props.get(key) {
+            Some(PropertyValue::Integer(i)) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Retrieves a float property.
+    ///
+    /// Returns `None` if the key doesn't exist or the value isn't a `PropertyValue::Float`.
+    /// Integer values are not converted.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The identifier of the property to retrieve
+    pub fn get_float(&self, key: &str) -> Option<f64> {
+        match self.props.get(key) {
+            Some(PropertyValue::Float(fl)) => Some(*fl),
+            _ => None,
+        }
+    }
+
+    /// Retrieves a boolean property.
+    ///
+    /// Returns `None` if the key doesn't exist or the value isn't a `PropertyValue::Boolean`.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The identifier of the property to retrieve
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.props.get(key) {
+            Some(PropertyValue::Boolean(b)) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Retrieves a date-time property as its ISO 8601 string.
+    ///
+    /// Returns `None` if the key doesn't exist or the value isn't a `PropertyValue::DateTime`.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The identifier of the property to retrieve
+    pub fn get_datetime(&self, key: &str) -> Option<&str> {
+        match self.props.get(key) {
+            Some(PropertyValue::DateTime(dt)) => Some(dt),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the collection contains a property for the given key.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The identifier of the property to look for
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.props.contains_key(key)
+    }
+
+    /// Returns an iterator over the property keys, in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.props.keys().map(String::as_str)
+    }
+
+    /// Returns an iterator over the property values, in arbitrary order.
+    pub fn values(&self) -> impl Iterator<Item = &PropertyValue> {
+        self.props.values()
+    }
+
+    /// Returns an iterator over the key-value pairs, in arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dater::{PropertyCollection, PropertyValue};
+    ///
+    /// let mut props = PropertyCollection::new();
+    /// props.set("count", PropertyValue::Integer(42));
+    ///
+    /// for (key, value) in props.iter() {
+    ///     println!("{} = {}", key, value);
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PropertyValue)> {
+        self.props.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Copies every property from `other` into this collection.
+    ///
+    /// When both collections have a property with the same key, the value from
+    /// `other` replaces the existing one.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The collection whose properties are copied
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dater::{PropertyCollection, PropertyValue};
+    ///
+    /// let mut config = PropertyCollection::new();
+    /// config.set("retries", PropertyValue::Integer(3));
+    ///
+    /// let mut overrides = PropertyCollection::new();
+    /// overrides.set("retries", PropertyValue::Integer(5));
+    ///
+    /// config.merge(&overrides);
+    /// assert_eq!(config.get_integer("retries"), Some(5));
+    /// ```
+    pub fn merge(&mut self, other: &PropertyCollection) {
+        for (key, value) in &other.props {
+            self.props.insert(key.clone(), value.clone());
+        }
+    }
+
+    /// Copies the properties from `other` whose keys aren't already in this collection.
+    ///
+    /// Existing values are never replaced, which makes this suitable for filling in
+    /// defaults.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The collection whose properties are copied
+    pub fn merge_non_destructive(&mut self, other: &PropertyCollection) {
+        for (key, value) in &other.props {
+            self.props
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+
     /// Removes a property from the collection.
     ///
     /// If the key doesn't exist, this operation has no effect.
@@ -212,7 +374,7 @@ mod tests {
         // Act
         collection.set("string", PropertyValue::String("text".to_string()));
         collection.set("integer", PropertyValue::Integer(42));
-        collection.set("float", PropertyValue::Float(3.14));
+        collection.set("float", PropertyValue::Float(2.5));
         collection.set("bool", PropertyValue::Boolean(true));
         collection.set(
             "date",
@@ -241,4 +403,211 @@ mod tests {
             Some(PropertyValue::DateTime(_))
         ));
     }
+
+    #[test]
+    fn test_typed_getters() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("string", PropertyValue::String("text".to_string()));
+        collection.set("integer", PropertyValue::Integer(42));
+        collection.set("float", PropertyValue::Float(2.5));
+        collection.set("bool", PropertyValue::Boolean(true));
+        collection.set(
+            "date",
+            PropertyValue::DateTime("2025-05-24T12:00:00Z".to_string()),
+        );
+
+        // Act & Assert
+        assert_eq!(collection.get_string("string"), Some("text"));
+        assert_eq!(collection.get_integer("integer"), Some(42));
+        assert_eq!(collection.get_float("float"), Some(2.5));
+        assert_eq!(collection.get_bool("bool"), Some(true));
+        assert_eq!(
+            collection.get_datetime("date"),
+            Some("2025-05-24T12:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_typed_getters_type_mismatch() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("integer", PropertyValue::Integer(42));
+        collection.set(
+            "date",
+            PropertyValue::DateTime("2025-05-24T12:00:00Z".to_string()),
+        );
+
+        // Act & Assert
+        assert_eq!(collection.get_string("integer"), None);
+        assert_eq!(collection.get_float("integer"), None);
+        assert_eq!(collection.get_bool("integer"), None);
+        assert_eq!(collection.get_datetime("integer"), None);
+        assert_eq!(collection.get_string("date"), None);
+        assert_eq!(collection.get_integer("date"), None);
+    }
+
+    #[test]
+    fn test_typed_getters_missing_key() {
+        // Arrange
+        let collection = PropertyCollection::new();
+
+        // Act & Assert
+        assert_eq!(collection.get_string("missing"), None);
+        assert_eq!(collection.get_integer("missing"), None);
+        assert_eq!(collection.get_float("missing"), None);
+        assert_eq!(collection.get_bool("missing"), None);
+        assert_eq!(collection.get_datetime("missing"), None);
+    }
+
+    #[test]
+    fn test_contains_key() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("present", PropertyValue::Boolean(false));
+
+        // Act & Assert
+        assert!(collection.contains_key("present"));
+        assert!(!collection.contains_key("absent"));
+    }
+
+    #[test]
+    fn test_keys_values_and_iter() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("a", PropertyValue::Integer(1));
+        collection.set("b", PropertyValue::Integer(2));
+        collection.set("c", PropertyValue::Integer(3));
+
+        // Act
+        let mut keys: Vec<&str> = collection.keys().collect();
+        keys.sort();
+        let mut values: Vec<i64> = collection
+            .values()
+            .map(|value| match value {
+                PropertyValue::Integer(i) => *i,
+                _ => panic!("Unexpected property type"),
+            })
+            .collect();
+        values.sort();
+        let mut pairs: Vec<(&str, &PropertyValue)> = collection.iter().collect();
+        pairs.sort_by_key(|(key, _)| *key);
+
+        // Assert
+        assert_eq!(keys, vec!["a", "b", "c"]);
+        assert_eq!(values, vec![1, 2, 3]);
+        assert_eq!(pairs.len(), collection.len());
+        assert_eq!(pairs[1], ("b", &PropertyValue::Integer(2)));
+    }
+
+    #[test]
+    fn test_merge_other_wins() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("shared", PropertyValue::Integer(1));
+        collection.set("mine", PropertyValue::Boolean(true));
+        let mut other = PropertyCollection::new();
+        other.set("shared", PropertyValue::Integer(2));
+        other.set("theirs", PropertyValue::String("value".to_string()));
+
+        // Act
+        collection.merge(&other);
+
+        // Assert
+        assert_eq!(collection.len(), 3);
+        assert_eq!(collection.get_integer("shared"), Some(2));
+        assert_eq!(collection.get_bool("mine"), Some(true));
+        assert_eq!(collection.get_string("theirs"), Some("value"));
+    }
+
+    #[test]
+    fn test_merge_non_destructive_keeps_existing() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("shared", PropertyValue::Integer(1));
+        let mut other = PropertyCollection::new();
+        other.set("shared", PropertyValue::Integer(2));
+        other.set("theirs", PropertyValue::Float(0.5));
+
+        // Act
+        collection.merge_non_destructive(&other);
+
+        // Assert
+        assert_eq!(collection.len(), 2);
+        assert_eq!(collection.get_integer("shared"), Some(1));
+        assert_eq!(collection.get_float("theirs"), Some(0.5));
+        assert_eq!(other.get_integer("shared"), Some(2), "Other is unchanged");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("string", PropertyValue::String("text".to_string()));
+        collection.set("integer", PropertyValue::Integer(42));
+        collection.set("float", PropertyValue::Float(2.5));
+        collection.set("bool", PropertyValue::Boolean(true));
+        collection.set(
+            "date",
+            PropertyValue::DateTime("2025-05-24T12:00:00Z".to_string()),
+        );
+
+        // Act
+        let json = serde_json::to_string(&collection).unwrap();
+        let restored: PropertyCollection = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(restored, collection);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_shape() {
+        // Arrange
+        let mut collection = PropertyCollection::new();
+        collection.set("count", PropertyValue::Integer(42));
+
+        // Act
+        let json = serde_json::to_value(&collection).unwrap();
+
+        // Assert
+        assert_eq!(json, serde_json::json!({ "count": { "Integer": 42 } }));
+    }
+
+    #[test]
+    fn test_equality() {
+        // Arrange
+        let mut first = PropertyCollection::new();
+        first.set("a", PropertyValue::Integer(1));
+        first.set("b", PropertyValue::String("two".to_string()));
+        let mut second = PropertyCollection::new();
+        second.set("b", PropertyValue::String("two".to_string()));
+        second.set("a", PropertyValue::Integer(1));
+
+        // Act & Assert
+        assert_eq!(first, second, "Insertion order doesn't matter");
+
+        second.set("a", PropertyValue::Float(1.0));
+        assert_ne!(first, second, "Different variants are not equal");
+
+        second.set("a", PropertyValue::Integer(1));
+        second.set("c", PropertyValue::Boolean(false));
+        assert_ne!(first, second, "Extra keys are not equal");
+    }
+
+    #[test]
+    fn test_clone_is_independent() {
+        // Arrange
+        let mut original = PropertyCollection::new();
+        original.set("a", PropertyValue::Integer(1));
+
+        // Act
+        let mut copy = original.clone();
+        copy.set("a", PropertyValue::Integer(2));
+
+        // Assert
+        assert_eq!(original.get_integer("a"), Some(1));
+        assert_eq!(copy.get_integer("a"), Some(2));
+    }
 }
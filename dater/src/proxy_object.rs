@@ -15,6 +15,8 @@
 //!
 //! // Access the wrapped object's methods directly
 //! assert_eq!(proxy.len(), 5);
+//! proxy.push_str(", world");
+//! assert_eq!(proxy.as_str(), "Hello, world");
 //!
 //! // Access custom properties
 //! assert_eq!(proxy.get("language").unwrap().to_string(), "en");
@@ -24,7 +26,8 @@
 //! assert_eq!(proxy.len(), 5);
 //! ```
 
-use std::ops::Deref;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
 
 use crate::{PropertyCollection, PropertyValue};
 
@@ -44,7 +47,32 @@ impl<T: ?Sized> Deref for ProxyObject<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &*self.object
+        &self.object
+    }
+}
+
+impl<T: ?Sized> DerefMut for ProxyObject<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for ProxyObject<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxyObject")
+            .field("object", &&*self.object)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl<T: Clone> Clone for ProxyObject<T> {
+    /// Creates a deep copy of the proxy, cloning both the wrapped object and its properties.
+    fn clone(&self) -> Self {
+        ProxyObject {
+            object: self.object.clone(),
+            properties: self.properties.clone(),
+        }
     }
 }
 
@@ -122,6 +150,45 @@ impl<T> ProxyObject<T> {
     pub fn get(&self, key: &str) -> Option<&PropertyValue> {
         self.properties.get(key)
     }
+
+    /// Consumes the proxy and returns the wrapped object, discarding its properties.
+    ///
+    /// # Examples
+    /// ```
+    /// use dater::{ProxyObject, PropertyValue};
+    ///
+    /// let mut proxy = ProxyObject::new(String::from("Hello"));
+    /// proxy.set("language", PropertyValue::String("en".to_string()));
+    ///
+    /// let inner: String = proxy.into_inner();
+    /// assert_eq!(inner, "Hello");
+    /// ```
+    pub fn into_inner(self) -> T {
+        *self.object
+    }
+
+    /// Transforms the wrapped object, keeping the existing properties.
+    ///
+    /// # Examples
+    /// ```
+    /// use dater::{ProxyObject, PropertyValue};
+    ///
+    /// let mut proxy = ProxyObject::new(21);
+    /// proxy.set("unit", PropertyValue::String("m".to_string()));
+    ///
+    /// let doubled = proxy.map(|value| value * 2);
+    /// assert_eq!(*doubled, 42);
+    /// assert_eq!(doubled.get("unit").unwrap().to_string(), "m");
+    /// ```
+    pub fn map<U, F>(self, f: F) -> ProxyObject<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        ProxyObject {
+            object: Box::new(f(*self.object)),
+            properties: self.properties,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -165,7 +232,7 @@ mod tests {
         }
 
         // Test different property types
-        proxy.set("float", PropertyValue::Float(3.14));
+        proxy.set("float", PropertyValue::Float(2.5));
         proxy.set("bool", PropertyValue::Boolean(true));
         proxy.set(
             "date",
@@ -173,7 +240,7 @@ mod tests {
         );
 
         if let PropertyValue::Float(val) = proxy.get("float").unwrap() {
-            assert_eq!(*val, 3.14);
+            assert_eq!(*val, 2.5);
         }
         if let PropertyValue::Boolean(val) = proxy.get("bool").unwrap() {
             assert!(val);
@@ -198,4 +265,86 @@ mod tests {
         };
         assert_eq!(&*proxy, "test");
     }
+
+    #[test]
+    fn test_proxy_deref_mut() {
+        let mut proxy = ProxyObject::new(vec![1, 2]);
+        proxy.push(3);
+
+        assert_eq!(proxy.len(), 3);
+        assert_eq!(*proxy, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_deref_mut_keeps_properties() {
+        let mut proxy = ProxyObject::new(String::from("a"));
+        proxy.set("key", PropertyValue::Boolean(true));
+
+        proxy.push('b');
+
+        assert_eq!(&*proxy, "ab");
+        assert_eq!(proxy.get("key"), Some(&PropertyValue::Boolean(true)));
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let mut proxy = ProxyObject::new(vec![1, 2, 3]);
+        proxy.set("key", PropertyValue::Integer(1));
+
+        let inner = proxy.into_inner();
+        assert_eq!(inner, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_map_preserves_properties() {
+        let mut proxy = ProxyObject::new(String::from("hello"));
+        proxy.set("language", PropertyValue::String("en".to_string()));
+        proxy.set("count", PropertyValue::Integer(2));
+
+        let mapped: ProxyObject<usize> = proxy.map(|s| s.len());
+
+        assert_eq!(*mapped, 5);
+        assert_eq!(
+            mapped.get("language"),
+            Some(&PropertyValue::String("en".to_string()))
+        );
+        assert_eq!(mapped.get("count"), Some(&PropertyValue::Integer(2)));
+    }
+
+    #[test]
+    fn test_debug_shows_object_and_properties() {
+        let mut proxy = ProxyObject::new(42);
+        proxy.set("key", PropertyValue::Boolean(true));
+
+        let output = format!("{:?}", proxy);
+
+        assert!(output.contains("object: 42"), "Got: {}", output);
+        assert!(output.contains("properties"), "Got: {}", output);
+        assert!(output.contains("Boolean(true)"), "Got: {}", output);
+    }
+
+    #[test]
+    fn test_debug_unsized() {
+        let proxy: ProxyObject<str> = ProxyObject {
+            object: Box::from("test"),
+            properties: PropertyCollection::new(),
+        };
+
+        assert!(format!("{:?}", proxy).contains("\"test\""));
+    }
+
+    #[test]
+    fn test_clone_is_independent() {
+        let mut original = ProxyObject::new(vec![1]);
+        original.set("key", PropertyValue::Integer(1));
+
+        let mut copy = original.clone();
+        copy.push(2);
+        copy.set("key", PropertyValue::Integer(2));
+
+        assert_eq!(*original, vec![1]);
+        assert_eq!(original.get("key"), Some(&PropertyValue::Integer(1)));
+        assert_eq!(*copy, vec![1, 2]);
+        assert_eq!(copy.get("key"), Some(&PropertyValue::Integer(2)));
+    }
 }
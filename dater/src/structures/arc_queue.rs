@@ -24,12 +24,15 @@ use crate::{LinearData, Queue};
 /// ```
 pub struct ArcQueue<T> {
     items: Arc<Mutex<Queue<T>>>,
+    /// The maximum number of items the queue may hold, or `None` if unbounded
+    capacity: Option<usize>,
 }
 
 impl<T> Clone for ArcQueue<T> {
     fn clone(&self) -> Self {
         ArcQueue {
             items: Arc::clone(&self.items),
+            capacity: self.capacity,
         }
     }
 }
@@ -38,10 +41,36 @@ impl<T> ArcQueue<T> {
     pub fn new() -> Self {
         ArcQueue {
             items: Arc::new(Mutex::new(Queue::new())),
+            capacity: None,
         }
     }
 
-    /// Adds an item to the back of the queue.
+    /// Creates a new queue that holds at most `capacity` items.
+    ///
+    /// Once the queue is full, `push` panics and `try_push` hands the item back until
+    /// a consumer pops an item. Clones of the queue share the same limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of items the queue may hold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dater::ArcQueue;
+    ///
+    /// let queue = ArcQueue::with_capacity(1);
+    /// assert_eq!(queue.try_push(1), Ok(()));
+    /// assert_eq!(queue.try_push(2), Err(2));
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArcQueue {
+            items: Arc::new(Mutex::new(Queue::new())),
+            capacity: Some(capacity),
+        }
+    }
+
+    /// Adds an item to the back of the queue if there is room for it.
     ///
     /// This operation acquires a mutex lock on the underlying queue. If the lock
     /// cannot be acquired (e.g., due to poison), this method will panic.
@@ -50,12 +79,43 @@ impl<T> ArcQueue<T> {
     ///
     /// * `item` - The item to add to the queue
     ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the item was added
+    /// * `Err(item)` - If the queue is at capacity, giving the item back to the caller
+    ///
     /// # Panics
     ///
     /// Panics if the mutex is poisoned.
-    pub fn push(&self, item: T) {
+    pub fn try_push(&self, item: T) -> Result<(), T> {
         let mut guard = self.items.lock().expect("Mutex was poisoned");
+        if self
+            .capacity
+            .is_some_and(|capacity| guard.len() >= capacity)
+        {
+            return Err(item);
+        }
         guard.push(item);
+        Ok(())
+    }
+
+    /// Adds an item to the back of the queue.
+    ///
+    /// This operation acquires a mutex lock on the underlying queue. If the lock
+    /// cannot be acquired (e.g., due to poison), this method will panic.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item to add to the queue
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is poisoned, or if the queue was created with `with_capacity`
+    /// and is full. Use `try_push` to handle a full queue without panicking.
+    pub fn push(&self, item: T) {
+        if self.try_push(item).is_err() {
+            panic!("ArcQueue is at capacity");
+        }
     }
 
     /// Removes and returns the item at the front of the queue.
@@ -92,6 +152,95 @@ impl<T> ArcQueue<T> {
         let guard = self.items.lock().expect("Mutex was poisoned");
         guard.is_empty()
     }
+
+    /// Returns the number of items in the queue.
+    ///
+    /// This operation acquires a mutex lock on the underlying queue. If the lock
+    /// cannot be acquired (e.g., due to poison), this method will panic.
+    ///
+    /// # Returns
+    ///
+    /// The number of items in the queue at the time of the call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is poisoned.
+    pub fn len(&self) -> usize {
+        let guard = self.items.lock().expect("Mutex was poisoned");
+        guard.len()
+    }
+
+    /// Calls `f` with a reference to the item at the front of the queue, without removing it.
+    ///
+    /// The lock is held only while `f` runs, so the reference can't outlive it. Keep `f`
+    /// short, as other threads are blocked from using the queue until it returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function receiving `Some(&T)` for the front item, or `None` if the queue
+    ///   is empty
+    ///
+    /// # Returns
+    ///
+    /// The value returned by `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dater::ArcQueue;
+    ///
+    /// let queue = ArcQueue::new();
+    /// queue.push(5);
+    ///
+    /// assert_eq!(queue.peek(|front| front.copied()), Some(5));
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    pub fn peek<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(Option<&T>) -> R,
+    {
+        let mut guard = self.items.lock().expect("Mutex was poisoned");
+        f(guard.peek())
+    }
+
+    /// Removes every item from the queue and returns them in FIFO order.
+    ///
+    /// The whole drain happens under a single lock, so items pushed concurrently either
+    /// land in the returned batch or stay in the queue for the next one; none are lost
+    /// or interleaved.
+    ///
+    /// # Returns
+    ///
+    /// The removed items, front of the queue first. Empty if the queue was empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dater::ArcQueue;
+    ///
+    /// let queue = ArcQueue::new();
+    /// queue.push(1);
+    /// queue.push(2);
+    ///
+    /// assert_eq!(queue.drain(), vec![1, 2]);
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn drain(&self) -> Vec<T> {
+        let mut guard = self.items.lock().expect("Mutex was poisoned");
+        let mut items = Vec::with_capacity(guard.len());
+        while let Some(item) = guard.pop() {
+            items.push(item);
+        }
+        items
+    }
 }
 
 impl<T> Default for ArcQueue<T> {
@@ -159,6 +308,122 @@ mod tests {
         let sum = consumer.join().unwrap();
 
         // Sum of numbers 0..100
-        assert_eq!(sum, (0..100).sum());
+        assert_eq!(sum, (0..100).sum::<i32>());
+    }
+
+    #[test]
+    fn test_try_push_when_full() {
+        let queue = ArcQueue::with_capacity(2);
+        assert_eq!(queue.try_push(1), Ok(()));
+        assert_eq!(queue.try_push(2), Ok(()));
+        assert_eq!(queue.try_push(3), Err(3));
+
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.try_push(3), Ok(()), "Popping frees a slot");
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "ArcQueue is at capacity")]
+    fn test_push_panics_when_full() {
+        let queue = ArcQueue::with_capacity(1);
+        queue.push(1);
+        queue.push(2);
+    }
+
+    #[test]
+    fn test_clone_shares_capacity() {
+        let queue = ArcQueue::with_capacity(1);
+        let queue_clone = queue.clone();
+
+        queue.push(1);
+        assert_eq!(queue_clone.try_push(2), Err(2));
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let queue = ArcQueue::with_capacity(0);
+        assert_eq!(queue.try_push(1), Err(1));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_unbounded_try_push() {
+        let queue = ArcQueue::new();
+        for i in 0..1000 {
+            assert_eq!(queue.try_push(i), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_len() {
+        let queue = ArcQueue::new();
+        assert_eq!(queue.len(), 0);
+
+        queue.push(1);
+        queue.push(2);
+        assert_eq!(queue.len(), 2);
+
+        queue.pop();
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn test_peek_does_not_remove() {
+        let queue = ArcQueue::new();
+        queue.push(1);
+        queue.push(2);
+
+        assert_eq!(queue.peek(|front| front.copied()), Some(1));
+        assert_eq!(queue.peek(|front| front.copied()), Some(1));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop(), Some(1));
+    }
+
+    #[test]
+    fn test_peek_empty_queue() {
+        let queue: ArcQueue<i32> = ArcQueue::new();
+        let called_with_none = queue.peek(|front| front.is_none());
+        assert!(called_with_none);
+    }
+
+    #[test]
+    fn test_drain_empty() {
+        let queue: ArcQueue<i32> = ArcQueue::new();
+        assert!(queue.drain().is_empty());
+    }
+
+    #[test]
+    fn test_drain_returns_items_in_order() {
+        let queue = ArcQueue::new();
+        for i in 0..5 {
+            queue.push(i);
+        }
+        queue.pop();
+        queue.push(5);
+
+        assert_eq!(queue.drain(), vec![1, 2, 3, 4, 5]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_drain_with_concurrent_push() {
+        let queue = ArcQueue::new();
+        let queue_clone = queue.clone();
+
+        let producer = thread::spawn(move || {
+            for i in 0..1000 {
+                queue_clone.push(i);
+            }
+        });
+
+        let mut drained = Vec::new();
+        while drained.len() < 1000 {
+            drained.extend(queue.drain());
+        }
+        producer.join().unwrap();
+
+        assert_eq!(drained, (0..1000).collect::<Vec<i32>>());
     }
 }
@@ -31,4 +31,44 @@ pub trait LinearData<T> {
     ///
     /// `true` if the structure contains no elements, `false` otherwise.
     fn is_empty(&self) -> bool;
+
+    /// Returns the number of items in the data structure.
+    ///
+    /// Implementations must keep this consistent with `is_empty`, so that
+    /// `is_empty() == (len() == 0)` always holds.
+    ///
+    /// # Returns
+    ///
+    /// The number of items currently stored.
+    fn len(&self) -> usize;
+
+    /// Removes every item from the data structure.
+    ///
+    /// After this call `len()` is `0` and `is_empty()` is `true`.
+    fn clear(&mut self);
+
+    /// Removes every item and returns them in the order they were popped.
+    ///
+    /// This is LIFO order for a `Stack` and FIFO order for a `Queue`. The data
+    /// structure is empty afterwards.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of all the items that were stored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dater::{LinearData, Stack};
+    /// let mut stack: Stack<i32> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(stack.collect_all(), vec![3, 2, 1]);
+    /// assert!(stack.is_empty());
+    /// ```
+    fn collect_all(&mut self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.len());
+        while let Some(item) = self.pop() {
+            items.push(item);
+        }
+        items
+    }
 }
@@ -24,6 +24,34 @@ impl<T> Queue<T> {
             backlog: Stack::new(),
         }
     }
+
+    /// Returns a reference to the item at the front of the queue without removing it.
+    ///
+    /// Unlike `LinearData::peek`, this only needs a shared reference, so it can be used
+    /// on a queue behind `&Queue<T>`. It doesn't move items between the internal stacks;
+    /// when none have been flushed yet, it walks the backlog to find the oldest item,
+    /// which takes time proportional to the number of items pushed since the last flush.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - Reference to the front item if the queue is not empty
+    /// * `None` - If the queue is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dater::{LinearData, Queue};
+    ///
+    /// let mut queue = Queue::new();
+    /// queue.push(1);
+    /// queue.push(2);
+    ///
+    /// let shared = &queue;
+    /// assert_eq!(shared.peek_immutable(), Some(&1));
+    /// ```
+    pub fn peek_immutable(&self) -> Option<&T> {
+        self.items.top().or_else(|| self.backlog.bottom())
+    }
 }
 
 impl<T> LinearData<T> for Queue<T> {
@@ -80,6 +108,21 @@ impl<T> LinearData<T> for Queue<T> {
     fn is_empty(&self) -> bool {
         self.items.is_empty() && self.backlog.is_empty()
     }
+
+    /// Returns the number of items in the queue.
+    ///
+    /// # Returns
+    ///
+    /// The number of items in the queue.
+    fn len(&self) -> usize {
+        self.items.len() + self.backlog.len()
+    }
+
+    /// Removes every element from the queue.
+    fn clear(&mut self) {
+        self.items.clear();
+        self.backlog.clear();
+    }
 }
 
 impl<T> Default for Queue<T> {
@@ -88,6 +131,27 @@ impl<T> Default for Queue<T> {
     }
 }
 
+/// Builds a queue by pushing each item in turn, so the first item of the iterator
+/// is at the front.
+///
+/// # Examples
+///
+/// ```
+/// use dater::{LinearData, Queue};
+///
+/// let mut queue: Queue<i32> = vec![1, 2, 3].into_iter().collect();
+/// assert_eq!(queue.pop(), Some(1));
+/// ```
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Queue::new();
+        for item in iter {
+            queue.push(item);
+        }
+        queue
+    }
+}
+
 impl<T> Iterator for Queue<T> {
     type Item = T;
 
@@ -148,7 +212,7 @@ mod tests {
         queue.push(2);
         queue.push(3);
 
-        let items: Vec<i32> = queue.into_iter().collect();
+        let items: Vec<i32> = queue.collect();
         assert_eq!(items, vec![1, 2, 3]);
     }
 
@@ -179,4 +243,103 @@ mod tests {
         let queue: Queue<i32> = Queue::default();
         assert!(queue.is_empty());
     }
+
+    #[test]
+    fn test_len_tracks_push_and_pop() {
+        let mut queue = Queue::new();
+        assert_eq!(queue.len(), 0);
+
+        queue.push(1);
+        queue.push(2);
+        queue.push(3);
+        assert_eq!(queue.len(), 3);
+
+        // Popping flushes the backlog, which must not change the count
+        queue.pop();
+        assert_eq!(queue.len(), 2);
+
+        queue.push(4);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some(&2));
+        assert_eq!(queue.len(), 3);
+
+        while queue.pop().is_some() {}
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut queue = Queue::new();
+        queue.push(1);
+        queue.push(2);
+        queue.pop();
+        queue.push(3);
+
+        queue.clear();
+        assert_eq!(queue.len(), 0);
+        assert!(queue.is_empty());
+        assert_eq!(queue.peek(), None);
+
+        queue.push(4);
+        assert_eq!(queue.pop(), Some(4));
+    }
+
+    #[test]
+    fn test_collect_all_is_fifo() {
+        let mut queue = Queue::new();
+        queue.push(1);
+        queue.push(2);
+        queue.pop();
+        queue.push(3);
+        queue.push(4);
+
+        assert_eq!(queue.collect_all(), vec![2, 3, 4]);
+        assert!(queue.is_empty());
+        assert!(queue.collect_all().is_empty());
+    }
+
+    #[test]
+    fn test_from_iterator_preserves_order() {
+        let queue: Queue<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(queue.len(), 3);
+
+        let items: Vec<i32> = queue.collect();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_from_empty_iterator() {
+        let queue: Queue<i32> = std::iter::empty().collect();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_peek_immutable() {
+        let mut queue = Queue::new();
+        assert_eq!(queue.peek_immutable(), None);
+
+        // Front item is still in the backlog
+        queue.push(1);
+        queue.push(2);
+        assert_eq!(queue.peek_immutable(), Some(&1));
+
+        // Front item has been flushed to the items stack
+        assert_eq!(queue.pop(), Some(1));
+        queue.push(3);
+        assert_eq!(queue.peek_immutable(), Some(&2));
+        assert_eq!(queue.len(), 2, "Peeking must not remove items");
+
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.peek_immutable(), Some(&3));
+    }
+
+    #[test]
+    fn test_peek_immutable_matches_peek() {
+        let mut queue: Queue<i32> = (1..=5).collect();
+        queue.pop();
+        queue.push(6);
+
+        let expected = queue.peek_immutable().copied();
+        assert_eq!(queue.peek().copied(), expected);
+    }
 }
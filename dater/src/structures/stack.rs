@@ -17,6 +17,7 @@ struct Node<T> {
 /// * `T` - The type of elements stored in the stack
 pub struct Stack<T> {
     head: Option<Box<Node<T>>>,
+    size: usize,
 }
 
 impl<T> Stack<T> {
@@ -26,7 +27,38 @@ impl<T> Stack<T> {
     ///
     /// A new `Stack<T>` instance.
     pub fn new() -> Self {
-        Stack { head: None }
+        Stack {
+            head: None,
+            size: 0,
+        }
+    }
+
+    /// Returns the number of elements in the stack.
+    ///
+    /// This is the same as `len`, and is tracked as elements are pushed and popped,
+    /// so it doesn't walk the list.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the stack.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns a reference to the top element without requiring mutable access.
+    pub(crate) fn top(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns a reference to the bottom element, the one pushed first.
+    ///
+    /// This walks the whole list, so it takes time proportional to the stack's length.
+    pub(crate) fn bottom(&self) -> Option<&T> {
+        let mut current = self.head.as_deref()?;
+        while let Some(previous) = current.previous.as_deref() {
+            current = previous;
+        }
+        Some(&current.value)
     }
 
     /// Reverses the order of elements in the stack.
@@ -59,6 +91,7 @@ impl<T> LinearData<T> for Stack<T> {
             previous: self.head.take(),
         });
         self.head = Some(new_node);
+        self.size += 1;
     }
 
     /// Removes and returns the top element from the stack.
@@ -70,6 +103,7 @@ impl<T> LinearData<T> for Stack<T> {
     fn pop(&mut self) -> Option<T> {
         self.head.take().map(|node| {
             self.head = node.previous;
+            self.size -= 1;
             node.value
         })
     }
@@ -81,7 +115,7 @@ impl<T> LinearData<T> for Stack<T> {
     /// * `Some(&T)` - Reference to the top element if the stack is not empty
     /// * `None` - If the stack is empty
     fn peek(&mut self) -> Option<&T> {
-        self.head.as_ref().map(|node| &node.value)
+        self.top()
     }
 
     /// Checks if the stack is empty.
@@ -92,11 +126,67 @@ impl<T> LinearData<T> for Stack<T> {
     fn is_empty(&self) -> bool {
         self.head.is_none()
     }
+
+    /// Returns the number of elements in the stack.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the stack.
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Removes every element from the stack.
+    ///
+    /// Elements are popped one at a time so that dropping a long stack doesn't
+    /// recurse through every node.
+    fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
 }
 
 impl<T> Default for Stack<T> {
     fn default() -> Self {
-        Stack { head: None }
+        Stack::new()
+    }
+}
+
+/// Creates a deep copy of the stack with the elements in the same order.
+///
+/// The copy shares no nodes with the original, so popping from one leaves the
+/// other untouched.
+impl<T: Clone> Clone for Stack<T> {
+    fn clone(&self) -> Self {
+        // Collect from the top down, then push from the bottom up to keep the order
+        let mut values = Vec::with_capacity(self.size);
+        let mut current = self.head.as_deref();
+        while let Some(node) = current {
+            values.push(&node.value);
+            current = node.previous.as_deref();
+        }
+
+        values.into_iter().rev().cloned().collect()
+    }
+}
+
+/// Builds a stack by pushing each item in turn, so the last item of the iterator
+/// ends up on top.
+///
+/// # Examples
+///
+/// ```
+/// use dater::{LinearData, Stack};
+///
+/// let mut stack: Stack<i32> = vec![1, 2, 3].into_iter().collect();
+/// assert_eq!(stack.pop(), Some(3));
+/// ```
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Stack::new();
+        for item in iter {
+            stack.push(item);
+        }
+        stack
     }
 }
 
@@ -158,7 +248,7 @@ mod tests {
         stack.push(2);
         stack.push(3);
 
-        let mut items: Vec<i32> = stack.into_iter().collect();
+        let mut items: Vec<i32> = stack.collect();
         items.reverse(); // Reverse to check original order
         assert_eq!(items, vec![1, 2, 3]);
     }
@@ -180,4 +270,120 @@ mod tests {
         let stack: Stack<i32> = Stack::default();
         assert!(stack.is_empty());
     }
+
+    #[test]
+    fn test_len_tracks_push_and_pop() {
+        let mut stack = Stack::new();
+        assert_eq!(stack.len(), 0);
+
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(stack.len(), 2);
+
+        stack.pop();
+        assert_eq!(stack.len(), 1);
+
+        stack.push(3);
+        stack.push(4);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.size(), 3);
+
+        while stack.pop().is_some() {}
+        assert_eq!(stack.len(), 0);
+
+        stack.pop();
+        assert_eq!(stack.len(), 0, "Popping an empty stack leaves len at zero");
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut stack: Stack<i32> = (0..100_000).collect();
+        stack.clear();
+
+        assert_eq!(stack.len(), 0);
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop(), None);
+
+        stack.push(1);
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.pop(), Some(1));
+    }
+
+    #[test]
+    fn test_collect_all_is_lifo() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        assert_eq!(stack.collect_all(), vec![3, 2, 1]);
+        assert!(stack.is_empty());
+        assert!(stack.collect_all().is_empty());
+    }
+
+    #[test]
+    fn test_reverse_keeps_len() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+
+        let reversed = stack.reverse();
+        assert_eq!(reversed.len(), 2);
+    }
+
+    #[test]
+    fn test_from_iterator_last_item_on_top() {
+        let stack: Stack<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(stack.len(), 3);
+
+        let items: Vec<i32> = stack.collect();
+        assert_eq!(items, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_from_empty_iterator() {
+        let stack: Stack<i32> = std::iter::empty().collect();
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_clone_is_independent() {
+        let original: Stack<i32> = vec![1, 2, 3].into_iter().collect();
+        let mut copy = original.clone();
+
+        assert_eq!(copy.pop(), Some(3));
+        assert_eq!(copy.len(), 2);
+        assert_eq!(
+            original.len(),
+            3,
+            "Popping the clone must not affect the original"
+        );
+    }
+
+    #[test]
+    fn test_clone_preserves_order() {
+        let original: Stack<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let copy = original.clone();
+
+        let original_items: Vec<String> = original.collect();
+        let copy_items: Vec<String> = copy.collect();
+        assert_eq!(original_items, copy_items);
+        assert_eq!(copy_items, vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_top_and_bottom() {
+        let mut stack = Stack::new();
+        assert_eq!(stack.top(), None);
+        assert_eq!(stack.bottom(), None);
+
+        stack.push(1);
+        assert_eq!(stack.top(), Some(&1));
+        assert_eq!(stack.bottom(), Some(&1));
+
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.top(), Some(&3));
+        assert_eq!(stack.bottom(), Some(&1));
+    }
 }
@@ -1,4 +1,5 @@
 mod logger;
+pub use logger::*;
 
 mod message;
 pub use message::*;
@@ -6,3 +7,4 @@ pub use message::*;
 pub mod sink;
 
 mod manager;
+pub use manager::*;
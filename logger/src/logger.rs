@@ -5,6 +5,7 @@ pub trait Logger {
 }
 
 pub trait LoggerHelpers {
+    fn trace(&self, message: String);
     fn debug(&self, message: String);
     fn verbose(&self, message: String);
     fn info(&self, message: String);
@@ -14,6 +15,10 @@ pub trait LoggerHelpers {
 }
 
 impl LoggerHelpers for dyn Logger {
+    fn trace(&self, message: String) {
+        self.log(LogMessage::Trace(message));
+    }
+
     fn debug(&self, message: String) {
         self.log(LogMessage::Debug(message));
     }
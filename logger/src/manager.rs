@@ -1,4 +1,34 @@
-use crate::{logger::Logger, sink::LoggerSink};
+use crate::{
+    logger::Logger,
+    sink::{FilteredSink, LoggerSink},
+    LogLevel, LogMessage, LogMessageFields,
+};
+
+/// The module recorded for messages logged without one.
+const DEFAULT_MODULE: &str = "default";
+
+/// Logs a message through a `LogManager`, recording the calling module's path.
+///
+/// The level is the name of a `LogMessage` variant, and the message accepts the
+/// same arguments as `format!`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use logger::{log, LogManager};
+///
+/// let manager = LogManager::new();
+/// log!(manager, Info, "Listening on port {}", 8080);
+/// ```
+#[macro_export]
+macro_rules! log {
+    ($manager:expr, $level:ident, $($arg:tt)+) => {
+        $manager.log_from(
+            ::std::module_path!(),
+            $crate::LogMessage::$level(::std::format!($($arg)+)),
+        )
+    };
+}
 
 pub struct LogManager {
     sinks: Vec<Box<dyn LoggerSink>>,
@@ -12,18 +42,156 @@ impl LogManager {
     pub fn add_sink(&mut self, sink: Box<dyn LoggerSink>) {
         self.sinks.push(sink);
     }
+
+    /// Adds a sink that only receives messages at or above `level`.
+    pub fn add_sink_with_level(&mut self, sink: Box<dyn LoggerSink>, level: LogLevel) {
+        self.add_sink(Box::new(FilteredSink::new(sink, level)));
+    }
+
+    /// Logs a message with structured key-value fields attached.
+    pub fn log_with_fields(&self, message: LogMessage, fields: LogMessageFields) {
+        self.dispatch(DEFAULT_MODULE, message, fields);
+    }
+
+    /// Logs a message on behalf of the given module, so sinks can tell which
+    /// subsystem it came from. The `log!` macro calls this with the caller's
+    /// module path.
+    pub fn log_from(&self, module: &str, message: LogMessage) {
+        self.dispatch(module, message, LogMessageFields::default());
+    }
+
+    fn dispatch(&self, module: &str, message: LogMessage, fields: LogMessageFields) {
+        let payload = crate::LogMessagePayload::new(message, module).with_fields(fields);
+
+        for sink in &self.sinks {
+            sink.write(payload.clone());
+        }
+    }
+}
+
+impl Default for LogManager {
+    fn default() -> Self {
+        LogManager::new()
+    }
 }
 
 impl Logger for LogManager {
     fn log(&self, message: crate::LogMessage) {
-        let payload = crate::LogMessagePayload {
-            message,
-            timestamp: timer::SystemTime::now(),
-            module: "default".to_string(), // Placeholder for module name
-        };
+        self.log_with_fields(message, LogMessageFields::default());
+    }
+}
 
-        for sink in &self.sinks {
-            sink.write(payload.clone());
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::MemorySink;
+    use std::cell::RefCell;
+
+    /// Records the arguments `log!` passes to `log_from`.
+    #[derive(Default)]
+    struct RecordingManager {
+        calls: RefCell<Vec<(String, LogMessage)>>,
+    }
+
+    impl RecordingManager {
+        fn log_from(&self, module: &str, message: LogMessage) {
+            self.calls.borrow_mut().push((module.to_string(), message));
         }
     }
+
+    #[test]
+    fn test_log_macro_records_calling_module() {
+        let manager = RecordingManager::default();
+
+        crate::log!(manager, Warning, "Disk {}% full", 90);
+
+        assert_eq!(
+            *manager.calls.borrow(),
+            vec![(
+                "logger::manager::tests".to_string(),
+                LogMessage::Warning("Disk 90% full".to_string())
+            )]
+        );
+    }
+
+    mod nested {
+        #[test]
+        fn test_log_macro_in_nested_module() {
+            let manager = super::RecordingManager::default();
+
+            crate::log!(manager, Info, "Hello");
+
+            assert_eq!(
+                manager.calls.borrow()[0].0,
+                "logger::manager::tests::nested"
+            );
+        }
+    }
+
+    #[test]
+    fn test_log_reaches_every_sink() {
+        let first = MemorySink::new();
+        let second = MemorySink::new();
+        let mut manager = LogManager::new();
+        manager.add_sink(Box::new(first.clone()));
+        manager.add_sink(Box::new(second.clone()));
+
+        manager.log(LogMessage::Info("Started".to_string()));
+
+        for sink in [first, second] {
+            let messages = sink.messages();
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].message, LogMessage::Info("Started".to_string()));
+            assert_eq!(messages[0].module, DEFAULT_MODULE);
+        }
+    }
+
+    #[test]
+    fn test_log_records_current_time() {
+        let sink = MemorySink::new();
+        let mut manager = LogManager::new();
+        manager.add_sink(Box::new(sink.clone()));
+
+        let before = timer::SystemTime::now();
+        manager.log(LogMessage::Debug("Tick".to_string()));
+        let after = timer::SystemTime::now();
+
+        let timestamp = sink.messages()[0].timestamp;
+        assert!(before <= timestamp && timestamp <= after);
+    }
+
+    #[test]
+    fn test_log_with_fields_and_module() {
+        let sink = MemorySink::new();
+        let mut manager = LogManager::new();
+        manager.add_sink(Box::new(sink.clone()));
+
+        manager.log_with_fields(
+            LogMessage::Info("Request".to_string()),
+            LogMessageFields::new().with("status", 200),
+        );
+        crate::log!(manager, Error, "Failed");
+
+        let messages = sink.messages();
+        assert_eq!(messages[0].fields.get("status"), Some("200"));
+        assert_eq!(messages[1].module, "logger::manager::tests");
+        assert!(messages[1].fields.is_empty());
+    }
+
+    #[test]
+    fn test_add_sink_with_level() {
+        let sink = MemorySink::new();
+        let mut manager = LogManager::new();
+        manager.add_sink_with_level(Box::new(sink.clone()), LogLevel::Warning);
+
+        manager.log(LogMessage::Info("Hidden".to_string()));
+        manager.log(LogMessage::Warning("Shown".to_string()));
+
+        let messages = sink.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0].message,
+            LogMessage::Warning("Shown".to_string())
+        );
+    }
 }
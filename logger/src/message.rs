@@ -1,7 +1,10 @@
+use std::{collections::HashMap, fmt};
+
 use timer::DateTime;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum LogMessage {
+    Trace(String),
     Debug(String),
     Verbose(String),
     Info(String),
@@ -10,9 +13,212 @@ pub enum LogMessage {
     Error(String),
 }
 
+/// The severity of a log message, ordered from least to most severe.
+///
+/// Used to filter messages, e.g. by `FilteredSink`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Verbose,
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for LogLevel {
+    /// Writes the upper-case level name, e.g. `INFO`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Verbose => "VERBOSE",
+            LogLevel::Info => "INFO",
+            LogLevel::Success => "SUCCESS",
+            LogLevel::Warning => "WARNING",
+            LogLevel::Error => "ERROR",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl LogMessage {
+    /// Returns the severity level of the message.
+    pub fn level(&self) -> LogLevel {
+        match self {
+            LogMessage::Trace(_) => LogLevel::Trace,
+            LogMessage::Debug(_) => LogLevel::Debug,
+            LogMessage::Verbose(_) => LogLevel::Verbose,
+            LogMessage::Info(_) => LogLevel::Info,
+            LogMessage::Success(_) => LogLevel::Success,
+            LogMessage::Warning(_) => LogLevel::Warning,
+            LogMessage::Error(_) => LogLevel::Error,
+        }
+    }
+
+    /// Returns the message text without the level.
+    pub fn text(&self) -> &str {
+        match self {
+            LogMessage::Trace(msg)
+            | LogMessage::Debug(msg)
+            | LogMessage::Verbose(msg)
+            | LogMessage::Info(msg)
+            | LogMessage::Success(msg)
+            | LogMessage::Warning(msg)
+            | LogMessage::Error(msg) => msg,
+        }
+    }
+}
+
+impl fmt::Display for LogMessage {
+    /// Writes the message prefixed with its level, e.g. `[INFO] Server started`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.level(), self.text())
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct LogMessageFields(HashMap<String, String>);
+
+impl LogMessageFields {
+    pub fn new() -> Self {
+        LogMessageFields(HashMap::new())
+    }
+
+    /// Adds a field, replacing any existing value for the key, and returns the fields.
+    pub fn with(mut self, key: &str, value: impl ToString) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Adds a field, replacing any existing value for the key.
+    pub fn insert(&mut self, key: &str, value: impl ToString) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    /// Returns the value of a field, or `None` if it isn't set.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Returns the fields as key-value pairs, sorted by key.
+    pub fn sorted(&self) -> Vec<(&str, &str)> {
+        let mut fields: Vec<(&str, &str)> = self
+            .0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for LogMessageFields {
+    /// Writes the fields as space-separated `key=value` pairs, sorted by key.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (key, value)) in self.sorted().into_iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LogMessagePayload {
     pub message: LogMessage,
     pub timestamp: DateTime,
     pub module: String,
+    pub fields: LogMessageFields,
+}
+
+impl LogMessagePayload {
+    /// Creates a payload for a message logged from the given module, stamped
+    /// with the current time and without any fields.
+    pub fn new(message: LogMessage, module: &str) -> Self {
+        LogMessagePayload {
+            message,
+            timestamp: timer::SystemTime::now(),
+            module: module.to_string(),
+            fields: LogMessageFields::default(),
+        }
+    }
+
+    /// Replaces the structured fields attached to the message.
+    pub fn with_fields(mut self, fields: LogMessageFields) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Replaces the timestamp, e.g. to get repeatable output in tests.
+    pub fn with_timestamp(mut self, timestamp: DateTime) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+}
+
+impl fmt::Display for LogMessagePayload {
+    /// Writes the message with its level, followed by any fields in `key=value` format,
+    /// e.g. `[INFO] Logged in user=alice`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if !self.fields.is_empty() {
+            write!(f, " {}", self.fields)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::test_payload;
+
+    #[test]
+    fn test_payload_display_with_fields() {
+        let fields = LogMessageFields::new().with("user", "alice").with("id", 42);
+
+        assert_eq!(
+            test_payload(LogMessage::Info("Logged in".to_string()))
+                .with_fields(fields)
+                .to_string(),
+            "[INFO] Logged in id=42 user=alice"
+        );
+    }
+
+    #[test]
+    fn test_payload_display_without_fields() {
+        assert_eq!(
+            test_payload(LogMessage::Info("Logged in".to_string())).to_string(),
+            "[INFO] Logged in"
+        );
+    }
+
+    #[test]
+    fn test_trace_display() {
+        let message = LogMessage::Trace("Entering loop".to_string());
+        assert_eq!(message.level(), LogLevel::Trace);
+        assert_eq!(message.to_string(), "[TRACE] Entering loop");
+    }
+
+    #[test]
+    fn test_fields_insert_replaces() {
+        let mut fields = LogMessageFields::new().with("key", "old");
+        fields.insert("key", "new");
+
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields.get("key"), Some("new"));
+        assert_eq!(fields.get("missing"), None);
+    }
 }
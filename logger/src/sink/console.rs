@@ -1,16 +1,33 @@
-use crate::{sink::LoggerSink, LogMessage, LogMessagePayload};
+use crate::{
+    sink::{DefaultFormatter, LoggerSink, SinkFormatter},
+    LogMessagePayload,
+};
 
-pub struct ConsoleSink {}
+/// A sink that prints each message to standard output.
+pub struct ConsoleSink {
+    formatter: Box<dyn SinkFormatter>,
+}
+
+impl ConsoleSink {
+    /// Creates a console sink that uses the `DefaultFormatter`.
+    pub fn new() -> Self {
+        ConsoleSink::with_formatter(Box::new(DefaultFormatter))
+    }
+
+    /// Creates a console sink that formats messages with `formatter`.
+    pub fn with_formatter(formatter: Box<dyn SinkFormatter>) -> Self {
+        ConsoleSink { formatter }
+    }
+}
+
+impl Default for ConsoleSink {
+    fn default() -> Self {
+        ConsoleSink::new()
+    }
+}
 
 impl LoggerSink for ConsoleSink {
     fn write(&self, message: LogMessagePayload) {
-        match message.message {
-            LogMessage::Debug(msg) => println!("[DEBUG] {}", msg),
-            LogMessage::Verbose(msg) => println!("[VERBOSE] {}", msg),
-            LogMessage::Info(msg) => println!("[INFO] {}", msg),
-            LogMessage::Success(msg) => println!("[SUCCESS] {}", msg),
-            LogMessage::Warning(msg) => println!("[WARNING] {}", msg),
-            LogMessage::Error(msg) => println!("[ERROR] {}", msg),
-        }
+        println!("{}", self.formatter.format(&message));
     }
 }
@@ -0,0 +1,165 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::{
+    sink::{DefaultFormatter, LoggerSink, SinkFormatter},
+    LogMessagePayload,
+};
+
+/// A sink that writes each log message to a file, one message per line.
+///
+/// Lines are formatted with the `DefaultFormatter` unless another formatter is
+/// given to `with_formatter`. Every message is flushed as it is written, so the
+/// file is up to date even if the process exits without dropping the sink.
+pub struct FileSink {
+    path: PathBuf,
+    writer: Mutex<BufWriter<File>>,
+    formatter: Box<dyn SinkFormatter>,
+}
+
+impl FileSink {
+    /// Opens the file at `path`, creating it if it doesn't exist.
+    ///
+    /// When `append` is `true`, messages are added after any existing content;
+    /// otherwise the file is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be opened or created.
+    pub fn new(path: impl Into<PathBuf>, append: bool) -> io::Result<Self> {
+        FileSink::with_formatter(path, append, Box::new(DefaultFormatter))
+    }
+
+    /// Opens the file at `path` like `new`, formatting messages with `formatter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be opened or created.
+    pub fn with_formatter(
+        path: impl Into<PathBuf>,
+        append: bool,
+        formatter: Box<dyn SinkFormatter>,
+    ) -> io::Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(&path)?;
+
+        Ok(FileSink {
+            path,
+            writer: Mutex::new(BufWriter::new(file)),
+            formatter,
+        })
+    }
+
+    /// Returns the path of the file being written to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl LoggerSink for FileSink {
+    fn write(&self, message: LogMessagePayload) {
+        let line = self.formatter.format(&message);
+        let mut writer = self.writer.lock().expect("Mutex was poisoned");
+        // A failing log write has nowhere to be reported, so it is dropped
+        let _ = writeln!(writer, "{}", line).and_then(|_| writer.flush());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        sink::{test_payload, SimpleFormatter},
+        LogMessage, LogMessageFields,
+    };
+    use std::fs;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("logger-{}-{}.log", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_writes_one_line_per_message() {
+        let path = temp_path("lines");
+        let sink = FileSink::with_formatter(&path, false, Box::new(SimpleFormatter)).unwrap();
+
+        sink.write(test_payload(LogMessage::Info("Started".to_string())));
+        sink.write(test_payload(LogMessage::Warning("Low disk".to_string())));
+        sink.write(test_payload(LogMessage::Error("Crashed".to_string())));
+
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            contents.lines().collect::<Vec<_>>(),
+            vec!["[INFO] Started", "[WARNING] Low disk", "[ERROR] Crashed"]
+        );
+    }
+
+    #[test]
+    fn test_append_keeps_existing_content() {
+        let path = temp_path("append");
+        fs::write(&path, "[INFO] Earlier\n").unwrap();
+
+        let sink = FileSink::with_formatter(&path, true, Box::new(SimpleFormatter)).unwrap();
+        sink.write(test_payload(LogMessage::Debug("Later".to_string())));
+
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(contents, "[INFO] Earlier\n[DEBUG] Later\n");
+    }
+
+    #[test]
+    fn test_truncate_replaces_existing_content() {
+        let path = temp_path("truncate");
+        fs::write(&path, "[INFO] Earlier\n").unwrap();
+
+        let sink = FileSink::with_formatter(&path, false, Box::new(SimpleFormatter)).unwrap();
+        sink.write(test_payload(LogMessage::Success("Fresh".to_string())));
+
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(contents, "[SUCCESS] Fresh\n");
+    }
+
+    #[test]
+    fn test_missing_directory_is_an_error() {
+        let path = temp_path("missing").join("nested.log");
+        assert!(FileSink::new(path, true).is_err());
+    }
+
+    #[test]
+    fn test_default_formatter_writes_module_and_fields() {
+        let path = temp_path("fields");
+        let sink = FileSink::new(&path, false).unwrap();
+
+        let fields = LogMessageFields::new()
+            .with("status", 200)
+            .with("path", "/index");
+        sink.write(test_payload(LogMessage::Info("Request".to_string())).with_fields(fields));
+        sink.write(test_payload(LogMessage::Info("Plain".to_string())));
+
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(
+            lines[0].ends_with("[INFO] [test] Request path=/index status=200"),
+            "Got: {}",
+            lines[0]
+        );
+        assert!(
+            lines[1].ends_with("[INFO] [test] Plain"),
+            "Got: {}",
+            lines[1]
+        );
+    }
+}
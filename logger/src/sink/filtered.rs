@@ -0,0 +1,108 @@
+use crate::{sink::LoggerSink, LogLevel, LogMessagePayload};
+
+/// A sink that forwards only messages at or above a minimum level to another sink.
+///
+/// # Examples
+///
+/// ```
+/// use logger::{
+///     sink::{ConsoleSink, FilteredSink},
+///     LogLevel,
+/// };
+///
+/// // Only warnings and errors reach the console
+/// let sink = FilteredSink::new(Box::new(ConsoleSink::new()), LogLevel::Warning);
+/// ```
+pub struct FilteredSink {
+    inner: Box<dyn LoggerSink>,
+    min_level: LogLevel,
+}
+
+impl FilteredSink {
+    /// Wraps `inner` so that messages below `min_level` are dropped.
+    pub fn new(inner: Box<dyn LoggerSink>, min_level: LogLevel) -> Self {
+        FilteredSink { inner, min_level }
+    }
+
+    /// Returns the least severe level that is forwarded.
+    pub fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+}
+
+impl LoggerSink for FilteredSink {
+    fn write(&self, message: LogMessagePayload) {
+        if message.message.level() >= self.min_level {
+            self.inner.write(message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sink::test_payload, LogMessage};
+    use std::sync::{Arc, Mutex};
+
+    struct RecordingSink(Arc<Mutex<Vec<LogMessage>>>);
+
+    impl LoggerSink for RecordingSink {
+        fn write(&self, message: LogMessagePayload) {
+            self.0.lock().unwrap().push(message.message);
+        }
+    }
+
+    #[test]
+    fn test_suppresses_messages_below_minimum() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = FilteredSink::new(Box::new(RecordingSink(received.clone())), LogLevel::Info);
+
+        sink.write(test_payload(LogMessage::Debug("hidden".to_string())));
+        sink.write(test_payload(LogMessage::Verbose("hidden".to_string())));
+        sink.write(test_payload(LogMessage::Info("shown".to_string())));
+        sink.write(test_payload(LogMessage::Error("shown".to_string())));
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![
+                LogMessage::Info("shown".to_string()),
+                LogMessage::Error("shown".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lowest_level_forwards_everything() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = FilteredSink::new(Box::new(RecordingSink(received.clone())), LogLevel::Debug);
+
+        sink.write(test_payload(LogMessage::Debug("a".to_string())));
+        sink.write(test_payload(LogMessage::Success("b".to_string())));
+
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_trace_suppressed_at_debug() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = FilteredSink::new(Box::new(RecordingSink(received.clone())), LogLevel::Debug);
+
+        sink.write(test_payload(LogMessage::Trace("hidden".to_string())));
+        sink.write(test_payload(LogMessage::Debug("shown".to_string())));
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![LogMessage::Debug("shown".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_levels_are_ordered_by_severity() {
+        assert!(LogLevel::Trace < LogLevel::Debug);
+        assert!(LogLevel::Debug < LogLevel::Verbose);
+        assert!(LogLevel::Verbose < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Success);
+        assert!(LogLevel::Success < LogLevel::Warning);
+        assert!(LogLevel::Warning < LogLevel::Error);
+    }
+}
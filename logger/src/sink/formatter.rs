@@ -0,0 +1,84 @@
+use crate::LogMessagePayload;
+
+/// Turns a log message into the line of text a sink writes.
+///
+/// Sinks such as `ConsoleSink` and `FileSink` take a boxed formatter, which keeps
+/// how a message looks separate from where it is written.
+///
+/// # Examples
+///
+/// ```
+/// use logger::{sink::SinkFormatter, LogMessagePayload};
+///
+/// /// Writes only the message text.
+/// struct TextOnly;
+///
+/// impl SinkFormatter for TextOnly {
+///     fn format(&self, payload: &LogMessagePayload) -> String {
+///         payload.message.text().to_string()
+///     }
+/// }
+/// ```
+pub trait SinkFormatter {
+    fn format(&self, payload: &LogMessagePayload) -> String;
+}
+
+/// Formats a message as `timestamp [LEVEL] [module] message`, followed by any fields
+/// in `key=value` format. The timestamp is written in ISO 8601.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct DefaultFormatter;
+
+impl SinkFormatter for DefaultFormatter {
+    fn format(&self, payload: &LogMessagePayload) -> String {
+        let mut line = format!(
+            "{} [{}] [{}] {}",
+            payload.timestamp.to_iso8601(),
+            payload.message.level(),
+            payload.module,
+            payload.message.text()
+        );
+        if !payload.fields.is_empty() {
+            line.push(' ');
+            line.push_str(&payload.fields.to_string());
+        }
+        line
+    }
+}
+
+/// Formats a message as `[LEVEL] message`, leaving out the module and fields.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct SimpleFormatter;
+
+impl SinkFormatter for SimpleFormatter {
+    fn format(&self, payload: &LogMessagePayload) -> String {
+        payload.message.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sink::test_payload, LogMessage, LogMessageFields};
+
+    fn saved(fields: LogMessageFields) -> LogMessagePayload {
+        test_payload(LogMessage::Success("Saved".to_string())).with_fields(fields)
+    }
+
+    #[test]
+    fn test_default_includes_module() {
+        let line = DefaultFormatter.format(&saved(LogMessageFields::new()));
+        assert_eq!(line, "1970-01-01T00:00:00Z [SUCCESS] [test] Saved");
+    }
+
+    #[test]
+    fn test_default_includes_fields() {
+        let line = DefaultFormatter.format(&saved(LogMessageFields::new().with("rows", 3)));
+        assert_eq!(line, "1970-01-01T00:00:00Z [SUCCESS] [test] Saved rows=3");
+    }
+
+    #[test]
+    fn test_simple_is_level_and_message_only() {
+        let line = SimpleFormatter.format(&saved(LogMessageFields::new().with("rows", 3)));
+        assert_eq!(line, "[SUCCESS] Saved");
+    }
+}
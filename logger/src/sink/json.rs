@@ -0,0 +1,136 @@
+use std::{
+    io::{self, Stdout, Write},
+    sync::Mutex,
+};
+
+use serde_json::{Map, Value};
+
+use crate::{sink::LoggerSink, LogMessagePayload};
+
+/// A sink that writes each message as a single-line JSON object.
+///
+/// Each line has the form
+/// `{"level":"INFO","msg":"...","timestamp":1704067200000,"module":"...","fields":{...}}`,
+/// which log aggregation tools can ingest directly. `timestamp` is in milliseconds
+/// since the Unix epoch.
+///
+/// Requires the `json` feature.
+pub struct JsonSink<W: Write> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write> JsonSink<W> {
+    /// Creates a sink that writes to `writer`, e.g. a `File`.
+    pub fn new(writer: W) -> Self {
+        JsonSink {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Consumes the sink and returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner().expect("Mutex was poisoned")
+    }
+
+    /// Formats a message as a JSON object, without a trailing newline.
+    fn format_line(message: &LogMessagePayload) -> String {
+        let fields: Map<String, Value> = message
+            .fields
+            .sorted()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), Value::from(value)))
+            .collect();
+
+        // Written by hand rather than through a map so the keys keep this order
+        format!(
+            "{{\"level\":{},\"msg\":{},\"timestamp\":{},\"module\":{},\"fields\":{}}}",
+            Value::from(message.message.level().to_string()),
+            Value::from(message.message.text()),
+            message.timestamp.unix_millis(),
+            Value::from(message.module.as_str()),
+            Value::Object(fields),
+        )
+    }
+}
+
+impl JsonSink<Stdout> {
+    /// Creates a sink that writes to standard output.
+    pub fn stdout() -> Self {
+        JsonSink::new(io::stdout())
+    }
+}
+
+impl<W: Write> LoggerSink for JsonSink<W> {
+    fn write(&self, message: LogMessagePayload) {
+        let line = Self::format_line(&message);
+        let mut writer = self.writer.lock().expect("Mutex was poisoned");
+        // A failing log write has nowhere to be reported, so it is dropped
+        let _ = writeln!(writer, "{}", line).and_then(|_| writer.flush());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sink::test_payload, LogMessage, LogMessageFields};
+
+    fn written_lines(sink: JsonSink<Vec<u8>>) -> Vec<Value> {
+        String::from_utf8(sink.into_inner())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_writes_one_object_per_line() {
+        let sink = JsonSink::new(Vec::new());
+
+        sink.write(
+            test_payload(LogMessage::Info("Connected".to_string()))
+                .with_timestamp(timer::DateTime::from_unix_millis(1_704_067_200_000)),
+        );
+        sink.write(
+            test_payload(LogMessage::Error("Query failed".to_string()))
+                .with_fields(LogMessageFields::new().with("table", "users")),
+        );
+
+        let lines = written_lines(sink);
+        assert_eq!(lines.len(), 2);
+
+        assert_eq!(lines[0]["level"], "INFO");
+        assert_eq!(lines[0]["msg"], "Connected");
+        assert_eq!(lines[0]["module"], "test");
+        assert_eq!(lines[0]["timestamp"].as_i64(), Some(1_704_067_200_000));
+        assert_eq!(lines[0]["fields"], serde_json::json!({}));
+
+        assert_eq!(lines[1]["level"], "ERROR");
+        assert_eq!(lines[1]["fields"]["table"], "users");
+    }
+
+    #[test]
+    fn test_escapes_special_characters() {
+        let sink = JsonSink::new(Vec::new());
+
+        sink.write(test_payload(LogMessage::Warning(
+            "Said \"hi\"\nthen left".to_string(),
+        )));
+
+        let output = String::from_utf8(sink.into_inner()).unwrap();
+        assert_eq!(output.lines().count(), 1, "Newlines must be escaped");
+
+        let value: Value = serde_json::from_str(output.trim_end()).unwrap();
+        assert_eq!(value["msg"], "Said \"hi\"\nthen left");
+    }
+
+    #[test]
+    fn test_key_order() {
+        let line =
+            JsonSink::<Vec<u8>>::format_line(&test_payload(LogMessage::Debug("x".to_string())));
+
+        assert_eq!(
+            line,
+            r#"{"level":"DEBUG","msg":"x","timestamp":0,"module":"test","fields":{}}"#
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{sink::LoggerSink, LogMessagePayload};
+
+/// A sink that keeps every message in memory, mainly for inspecting logs in tests.
+///
+/// Clones share the same storage, so one clone can be handed to a `LogManager`
+/// while another is used to read back what was logged.
+///
+/// # Examples
+///
+/// ```
+/// use logger::{
+///     sink::{LoggerSink, MemorySink},
+///     LogMessage, LogMessagePayload,
+/// };
+///
+/// let sink = MemorySink::new();
+/// let reader = sink.clone();
+///
+/// sink.write(LogMessagePayload::new(LogMessage::Info("Started".to_string()), "app"));
+///
+/// assert_eq!(reader.messages()[0].message, LogMessage::Info("Started".to_string()));
+/// ```
+#[derive(Clone, Default)]
+pub struct MemorySink {
+    messages: Arc<Mutex<Vec<LogMessagePayload>>>,
+}
+
+impl MemorySink {
+    pub fn new() -> Self {
+        MemorySink::default()
+    }
+
+    /// Returns a copy of every message received so far, oldest first.
+    pub fn messages(&self) -> Vec<LogMessagePayload> {
+        self.messages.lock().expect("Mutex was poisoned").clone()
+    }
+
+    /// Removes all stored messages.
+    pub fn clear(&self) {
+        self.messages.lock().expect("Mutex was poisoned").clear();
+    }
+}
+
+impl LoggerSink for MemorySink {
+    fn write(&self, message: LogMessagePayload) {
+        self.messages
+            .lock()
+            .expect("Mutex was poisoned")
+            .push(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        sink::{test_payload, FilteredSink},
+        LogLevel, LogMessage,
+    };
+
+    /// Stands in for application code that logs through a sink it was given.
+    fn process_order(sink: &dyn LoggerSink, id: u32) {
+        sink.write(test_payload(LogMessage::Debug(format!(
+            "Loading order {}",
+            id
+        ))));
+        sink.write(test_payload(LogMessage::Info(format!(
+            "Processed order {}",
+            id
+        ))));
+    }
+
+    #[test]
+    fn test_records_messages_in_order() {
+        let sink = MemorySink::new();
+
+        process_order(&sink, 1);
+        process_order(&sink, 2);
+
+        let messages: Vec<LogMessage> = sink
+            .messages()
+            .into_iter()
+            .map(|payload| payload.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                LogMessage::Debug("Loading order 1".to_string()),
+                LogMessage::Info("Processed order 1".to_string()),
+                LogMessage::Debug("Loading order 2".to_string()),
+                LogMessage::Info("Processed order 2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clones_share_storage() {
+        let sink = MemorySink::new();
+        let filtered = FilteredSink::new(Box::new(sink.clone()), LogLevel::Info);
+
+        process_order(&filtered, 7);
+
+        let messages = sink.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0].message,
+            LogMessage::Info("Processed order 7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_clear() {
+        let sink = MemorySink::new();
+        process_order(&sink, 1);
+
+        sink.clear();
+
+        assert!(sink.messages().is_empty());
+    }
+}
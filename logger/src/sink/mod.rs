@@ -4,5 +4,32 @@ pub trait LoggerSink {
     fn write(&self, message: LogMessagePayload);
 }
 
+mod formatter;
+pub use formatter::*;
+
 mod console;
 pub use console::*;
+
+mod file;
+pub use file::*;
+
+mod rotating_file;
+pub use rotating_file::*;
+
+mod filtered;
+pub use filtered::*;
+
+mod memory;
+pub use memory::*;
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::*;
+
+/// Wraps a message in a payload from the `test` module, stamped with the Unix
+/// epoch so formatted output is repeatable.
+#[cfg(test)]
+pub(crate) fn test_payload(message: crate::LogMessage) -> LogMessagePayload {
+    LogMessagePayload::new(message, "test").with_timestamp(timer::DateTime::default())
+}
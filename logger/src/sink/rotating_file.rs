@@ -0,0 +1,203 @@
+use std::{
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::{
+    sink::{FileSink, LoggerSink},
+    LogMessagePayload,
+};
+
+/// A file sink that starts a new file once the current one grows too large.
+///
+/// After a write leaves the file larger than `max_bytes`, the file is rotated:
+/// `app.log` is renamed to `app.log.1`, `app.log.1` to `app.log.2`, and so on, keeping
+/// at most `max_files` backups. The oldest backup is deleted when the limit is reached,
+/// and a fresh `app.log` is started for the next message.
+pub struct RotatingFileSink {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    /// The sink for the current file, or `None` if reopening it after a rotation failed
+    current: Mutex<Option<FileSink>>,
+}
+
+impl RotatingFileSink {
+    /// Opens the log file at `path`, appending to it if it already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the active log file; backups are written next to it
+    /// * `max_bytes` - The size a file may reach before it is rotated
+    /// * `max_files` - The number of backups to keep; `0` discards the old file on rotation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be opened or created.
+    pub fn new(path: impl Into<PathBuf>, max_bytes: u64, max_files: usize) -> io::Result<Self> {
+        let path = path.into();
+        let sink = FileSink::new(&path, true)?;
+
+        Ok(RotatingFileSink {
+            path,
+            max_bytes,
+            max_files,
+            current: Mutex::new(Some(sink)),
+        })
+    }
+
+    /// Returns the path of the numbered backup, e.g. `app.log.2` for `index` 2.
+    fn backup_path(&self, index: usize) -> PathBuf {
+        let mut name = OsString::from(self.path.as_os_str());
+        name.push(format!(".{}", index));
+        PathBuf::from(name)
+    }
+
+    /// Shifts every backup up by one and moves the active file into the first slot.
+    fn rotate(&self) -> io::Result<()> {
+        if self.max_files == 0 {
+            return fs::remove_file(&self.path);
+        }
+
+        let oldest = self.backup_path(self.max_files);
+        if oldest.exists() {
+            fs::remove_file(oldest)?;
+        }
+        for index in (1..self.max_files).rev() {
+            let backup = self.backup_path(index);
+            if backup.exists() {
+                fs::rename(backup, self.backup_path(index + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.backup_path(1))
+    }
+
+    fn size(path: &Path) -> u64 {
+        fs::metadata(path)
+            .map(|metadata| metadata.len())
+            .unwrap_or(0)
+    }
+}
+
+impl LoggerSink for RotatingFileSink {
+    fn write(&self, message: LogMessagePayload) {
+        let mut current = self.current.lock().expect("Mutex was poisoned");
+        if current.is_none() {
+            *current = FileSink::new(&self.path, true).ok();
+        }
+        let Some(sink) = current.as_ref() else {
+            return;
+        };
+
+        sink.write(message);
+
+        if Self::size(&self.path) > self.max_bytes {
+            // Close the file before renaming it, which some platforms require
+            *current = None;
+            if self.rotate().is_ok() {
+                *current = FileSink::new(&self.path, false).ok();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        sink::{test_payload, DefaultFormatter, SinkFormatter},
+        LogMessage,
+    };
+
+    /// The message logged with the given index.
+    fn message(index: usize) -> LogMessage {
+        LogMessage::Info(format!("message {}", index))
+    }
+
+    /// The line written for the message with the given index.
+    fn line(index: usize) -> String {
+        DefaultFormatter.format(&test_payload(message(index)))
+    }
+
+    /// The number of bytes a single-digit message takes up, including the newline.
+    fn line_len() -> u64 {
+        line(0).len() as u64 + 1
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("logger-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn read_lines(path: &Path) -> Vec<String> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_rotates_and_keeps_newest_backups() {
+        let dir = temp_dir("rotate");
+        let path = dir.join("app.log");
+        // Every file holds three messages before rotating
+        let sink = RotatingFileSink::new(&path, line_len() * 3 - 1, 2).unwrap();
+
+        for index in 0..10 {
+            sink.write(test_payload(message(index)));
+        }
+
+        let current = read_lines(&path);
+        let first = read_lines(&dir.join("app.log.1"));
+        let second = read_lines(&dir.join("app.log.2"));
+        let file_count = fs::read_dir(&dir).unwrap().count();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(file_count, 3, "The active file plus two backups");
+        assert_eq!(current, vec![line(9)]);
+        assert_eq!(first, vec![line(6), line(7), line(8)]);
+        assert_eq!(second, vec![line(3), line(4), line(5)]);
+    }
+
+    #[test]
+    fn test_no_rotation_below_limit() {
+        let dir = temp_dir("below-limit");
+        let path = dir.join("app.log");
+        let sink = RotatingFileSink::new(&path, line_len() * 5, 3).unwrap();
+
+        for index in 0..5 {
+            sink.write(test_payload(message(index)));
+        }
+
+        let current = read_lines(&path);
+        let file_count = fs::read_dir(&dir).unwrap().count();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(file_count, 1);
+        assert_eq!(current.len(), 5);
+    }
+
+    #[test]
+    fn test_zero_backups_discards_old_file() {
+        let dir = temp_dir("zero-backups");
+        let path = dir.join("app.log");
+        // Every file holds two messages before rotating
+        let sink = RotatingFileSink::new(&path, line_len() * 2 - 1, 0).unwrap();
+
+        for index in 0..3 {
+            sink.write(test_payload(message(index)));
+        }
+
+        let current = read_lines(&path);
+        let file_count = fs::read_dir(&dir).unwrap().count();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(file_count, 1);
+        assert_eq!(current, vec![line(2)]);
+    }
+}
@@ -0,0 +1,66 @@
+//! Numerical integration of a curve's speed, used by the arc-length methods of
+//! [`ParametricCurve`].
+
+use crate::curve::ParametricCurve;
+
+/// The number of equal parameter panels the domain is split into. Each panel is
+/// integrated separately, which keeps the quadrature accurate on curves whose
+/// speed varies a lot across the domain.
+pub(crate) const PANELS: usize = 64;
+
+/// Nodes and weights of five-point Gauss-Legendre quadrature on `[-1, 1]`.
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.0, 0.568_888_888_888_888_9),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// Returns `|dP/dt|` at `t`, estimated with a central difference of step `h`.
+fn speed<C, const N: usize>(curve: &C, t: f64, h: f64) -> f64
+where
+    C: ParametricCurve<N> + ?Sized,
+{
+    curve.point(t - h).distance(&curve.point(t + h)) / (2.0 * h)
+}
+
+/// Returns the length of the curve between parameters `a` and `b`, using a single
+/// Gauss-Legendre panel. The result is positive whichever way round `a` and `b` are.
+pub(crate) fn length_between<C, const N: usize>(curve: &C, a: f64, b: f64) -> f64
+where
+    C: ParametricCurve<N> + ?Sized,
+{
+    let half_width = (b - a) / 2.0;
+    if half_width == 0.0 {
+        return 0.0;
+    }
+
+    let midpoint = (a + b) / 2.0;
+    let domain = curve.get_domain();
+    let h = (domain.end - domain.start).abs() * 1e-5;
+    let total: f64 = GAUSS_LEGENDRE
+        .iter()
+        .map(|&(node, weight)| weight * speed(curve, midpoint + half_width * node, h))
+        .sum();
+    total * half_width.abs()
+}
+
+/// Returns the parameter at the boundary of each panel, from the start of the
+/// domain to its end, along with the cumulative length up to each boundary.
+pub(crate) fn panels<C, const N: usize>(curve: &C) -> (Vec<f64>, Vec<f64>)
+where
+    C: ParametricCurve<N> + ?Sized,
+{
+    let domain = curve.get_domain();
+    let bounds: Vec<f64> = domain.sample_n(PANELS + 1).collect();
+
+    let mut lengths = Vec::with_capacity(bounds.len());
+    lengths.push(0.0);
+    for pair in bounds.windows(2) {
+        let previous = lengths[lengths.len() - 1];
+        lengths.push(previous + length_between(curve, pair[0], pair[1]));
+    }
+
+    (bounds, lengths)
+}
@@ -0,0 +1,148 @@
+use crate::{curve::ParametricCurve, Domain, Point};
+
+/// A Bézier curve of any degree, defined by its control points.
+///
+/// The curve starts at the first control point and ends at the last. The points
+/// in between pull the curve towards them without, in general, lying on it. A
+/// curve with `k` control points has degree `k - 1`, so three points give a
+/// quadratic curve and four give a cubic.
+///
+/// # Examples
+/// ```
+/// use mather::{curve::{BezierCurve, ParametricCurve}, Point};
+///
+/// let curve = BezierCurve::new(vec![
+///     Point::new([0.0, 0.0]),
+///     Point::new([1.0, 2.0]),
+///     Point::new([2.0, 0.0]),
+/// ]);
+/// assert_eq!(curve.point(0.5), Point::new([1.0, 1.0]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BezierCurve<const N: usize> {
+    control_points: Vec<Point<N>>,
+}
+
+impl<const N: usize> BezierCurve<N> {
+    /// Creates a Bézier curve from its control points.
+    ///
+    /// # Arguments
+    /// * `control_points` - The control points, from the start of the curve to its end
+    ///
+    /// # Panics
+    /// Panics if there are fewer than 2 control points.
+    pub fn new(control_points: Vec<Point<N>>) -> Self {
+        assert!(
+            control_points.len() >= 2,
+            "BezierCurve needs at least 2 control points, got {}",
+            control_points.len()
+        );
+        BezierCurve { control_points }
+    }
+
+    /// Returns the control points of the curve.
+    pub fn control_points(&self) -> &[Point<N>] {
+        &self.control_points
+    }
+
+    /// Returns the degree of the curve, one less than the number of control points.
+    pub fn degree(&self) -> usize {
+        self.control_points.len() - 1
+    }
+}
+
+impl<const N: usize> ParametricCurve<N> for BezierCurve<N> {
+    /// Always `[0, 1]`.
+    fn get_domain(&self) -> Domain {
+        Domain::unit_domain()
+    }
+
+    /// Evaluates the curve with de Casteljau's algorithm, which repeatedly
+    /// interpolates between neighbouring points until one is left.
+    fn point(&self, t: f64) -> Point<N> {
+        let mut points: Vec<[f64; N]> = self
+            .control_points
+            .iter()
+            .map(|point| *point.coords())
+            .collect();
+
+        for level in (1..points.len()).rev() {
+            for i in 0..level {
+                points[i] = std::array::from_fn(|axis| {
+                    points[i][axis] + (points[i + 1][axis] - points[i][axis]) * t
+                });
+            }
+        }
+
+        Point::new(points[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quadratic() -> BezierCurve<2> {
+        BezierCurve::new(vec![
+            Point::new([0.0, 0.0]),
+            Point::new([2.0, 4.0]),
+            Point::new([6.0, 2.0]),
+        ])
+    }
+
+    fn assert_close(a: Point<2>, b: Point<2>) {
+        for axis in 0..2 {
+            assert!(
+                (a.coords()[axis] - b.coords()[axis]).abs() < 1e-12,
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn test_quadratic_endpoints() {
+        let curve = quadratic();
+
+        assert_eq!(curve.degree(), 2);
+        assert_eq!(curve.point(0.0), Point::new([0.0, 0.0]));
+        assert_eq!(curve.point(1.0), Point::new([6.0, 2.0]));
+    }
+
+    #[test]
+    fn test_quadratic_midpoint() {
+        // B(0.5) = (P0 + 2 P1 + P2) / 4
+        assert_eq!(quadratic().point(0.5), Point::new([2.5, 2.5]));
+    }
+
+    #[test]
+    fn test_cubic_matches_bernstein_form() {
+        let p = [[0.0, 0.0], [1.0, 3.0], [4.0, 3.0], [5.0, 0.0]];
+        let curve = BezierCurve::new(p.iter().map(|&c| Point::new(c)).collect());
+
+        for t in [0.0, 0.2, 0.5, 0.9, 1.0] {
+            let u = 1.0 - t;
+            let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+            let expected = Point::new(std::array::from_fn(|axis| {
+                (0..4).map(|i| weights[i] * p[i][axis]).sum()
+            }));
+            assert_close(curve.point(t), expected);
+        }
+    }
+
+    #[test]
+    fn test_linear_curve_is_a_segment() {
+        let curve = BezierCurve::new(vec![Point::new([1.0, 1.0]), Point::new([3.0, 5.0])]);
+
+        assert_eq!(curve.get_domain(), Domain::new(0.0, 1.0));
+        assert_close(curve.point(0.25), Point::new([1.5, 2.0]));
+        assert!((curve.arc_length() - 20f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 control points")]
+    fn test_too_few_control_points() {
+        BezierCurve::new(vec![Point::new([0.0, 0.0])]);
+    }
+}
@@ -12,10 +12,6 @@ impl<const N: usize> ParametricCurve<N> for Line<N> {
     }
 
     fn point(&self, t: f64) -> Point<N> {
-        let mut coords = [0.0; N];
-        for i in 0..N {
-            coords[i] = self.components[i] * t;
-        }
-        Point { coords }
+        Point::new(std::array::from_fn(|i| self.components[i] * t))
     }
 }
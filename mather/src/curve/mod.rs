@@ -1,5 +1,13 @@
+mod arc_length;
+
 mod parametric;
 pub use parametric::*;
 
 mod line;
 pub use line::*;
+
+mod bezier;
+pub use bezier::*;
+
+mod polyline;
+pub use polyline::*;
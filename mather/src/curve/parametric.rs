@@ -1,6 +1,207 @@
-use crate::{Domain, Point};
+use crate::{
+    curve::arc_length::{length_between, panels},
+    Domain, Point,
+};
 
+/// A curve in N-dimensional space traced out by a parameter `t` over a domain.
 pub trait ParametricCurve<const N: usize> {
     fn get_domain(&self) -> Domain;
     fn point(&self, t: f64) -> Point<N>;
+
+    /// Evaluates the curve at `n` evenly spaced parameter values across its domain.
+    ///
+    /// The parameters come from `Domain::sample_n`, so the first point is at the
+    /// start of the domain and the last is at its end. A count of 1 yields only
+    /// the start point, and a count of 0 yields nothing.
+    ///
+    /// # Arguments
+    /// * `n` - The number of points to return
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::{curve::{ParametricCurve, Polyline}, Point};
+    ///
+    /// let polyline = Polyline::new(vec![Point::new([0.0]), Point::new([4.0])]);
+    /// let points = polyline.sample_points(3);
+    /// assert_eq!(points, vec![Point::new([0.0]), Point::new([2.0]), Point::new([4.0])]);
+    /// ```
+    fn sample_points(&self, n: usize) -> Vec<Point<N>> {
+        self.get_domain()
+            .sample_n(n)
+            .map(|t| self.point(t))
+            .collect()
+    }
+
+    /// Returns the length of the curve over its whole domain.
+    ///
+    /// The length is the integral of the speed `|dP/dt|` over the domain. The
+    /// speed is estimated with central differences and integrated with
+    /// Gauss-Legendre quadrature on equal parameter panels, so the result is an
+    /// approximation that is very accurate for smooth curves.
+    fn arc_length(&self) -> f64 {
+        let (_, lengths) = panels(self);
+        lengths[lengths.len() - 1]
+    }
+
+    /// Returns the point at a given distance along the curve from the start of
+    /// its domain.
+    ///
+    /// Unlike `point`, equal steps in `s` give points that are equally far apart
+    /// along the curve. Distances below `0` or beyond `arc_length()` are clamped
+    /// to the ends of the curve.
+    ///
+    /// # Arguments
+    /// * `s` - The distance along the curve
+    fn arc_length_point(&self, s: f64) -> Point<N> {
+        let (bounds, lengths) = panels(self);
+        let total = lengths[lengths.len() - 1];
+        if s <= 0.0 || total == 0.0 {
+            return self.point(bounds[0]);
+        }
+        if s >= total {
+            return self.point(bounds[bounds.len() - 1]);
+        }
+
+        // The panel containing `s`, then bisect for the parameter within it
+        let panel = lengths.partition_point(|&length| length <= s) - 1;
+        let remaining = s - lengths[panel];
+        let (mut low, mut high) = (bounds[panel], bounds[panel + 1]);
+        for _ in 0..50 {
+            let middle = (low + high) / 2.0;
+            if length_between(self, bounds[panel], middle) < remaining {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        self.point((low + high) / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `P(t) = direction * t`
+    struct Ray {
+        direction: [f64; 2],
+        domain: Domain,
+    }
+
+    impl ParametricCurve<2> for Ray {
+        fn get_domain(&self) -> Domain {
+            self.domain
+        }
+
+        fn point(&self, t: f64) -> Point<2> {
+            Point::new([self.direction[0] * t, self.direction[1] * t])
+        }
+    }
+
+    /// `P(t) = (t, t²)`
+    struct Parabola;
+
+    impl ParametricCurve<2> for Parabola {
+        fn get_domain(&self) -> Domain {
+            Domain::new(0.0, 2.0)
+        }
+
+        fn point(&self, t: f64) -> Point<2> {
+            Point::new([t, t * t])
+        }
+    }
+
+    /// The length of `Parabola` from `t = 0` to `t = x`, `∫₀ˣ √(1 + 4t²) dt`.
+    fn parabola_length(x: f64) -> f64 {
+        let root = (1.0 + 4.0 * x * x).sqrt();
+        x * root / 2.0 + (2.0 * x + root).ln() / 4.0
+    }
+
+    #[test]
+    fn test_unit_speed_line_length_is_parameter_range() {
+        let line = Ray {
+            direction: [0.6, 0.8],
+            domain: Domain::new(-2.0, 3.0),
+        };
+
+        assert!((line.arc_length() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_arc_length_of_parabola() {
+        assert!((Parabola.arc_length() - parabola_length(2.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_arc_length_point_on_line() {
+        let line = Ray {
+            direction: [3.0, 4.0],
+            domain: Domain::new(0.0, 1.0),
+        };
+
+        let point = line.arc_length_point(2.5);
+
+        assert!(point.distance(&Point::new([1.5, 2.0])) < 1e-9);
+    }
+
+    #[test]
+    fn test_arc_length_spacing_is_uniform() {
+        let total = Parabola.arc_length();
+
+        for i in 0..=20 {
+            let s = total * i as f64 / 20.0;
+            let point = Parabola.arc_length_point(s);
+
+            // The x coordinate is the parameter, so the exact distance is known
+            assert!((parabola_length(point.coords()[0]) - s).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_arc_length_point_clamps() {
+        let total = Parabola.arc_length();
+
+        assert_eq!(Parabola.arc_length_point(-1.0), Point::new([0.0, 0.0]));
+        assert_eq!(
+            Parabola.arc_length_point(total + 1.0),
+            Point::new([2.0, 4.0])
+        );
+    }
+
+    #[test]
+    fn test_sample_points_spans_domain() {
+        let domain = Parabola.get_domain();
+        let points = Parabola.sample_points(9);
+
+        assert_eq!(points.len(), 9);
+        assert_eq!(points[0], Parabola.point(domain.start));
+        assert_eq!(points[8], Parabola.point(domain.end));
+        assert_eq!(points[4], Point::new([1.0, 1.0]));
+    }
+
+    #[test]
+    fn test_sample_points_reversed_domain() {
+        let line = Ray {
+            direction: [1.0, 2.0],
+            domain: Domain::new(2.0, 0.0),
+        };
+
+        let points = line.sample_points(3);
+
+        assert_eq!(
+            points,
+            vec![
+                Point::new([2.0, 4.0]),
+                Point::new([1.0, 2.0]),
+                Point::new([0.0, 0.0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sample_points_small_counts() {
+        assert!(Parabola.sample_points(0).is_empty());
+        assert_eq!(Parabola.sample_points(1), vec![Point::new([0.0, 0.0])]);
+    }
 }
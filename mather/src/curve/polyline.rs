@@ -0,0 +1,197 @@
+use crate::{curve::ParametricCurve, Domain, Point};
+
+/// A piecewise-linear curve through a list of vertices.
+///
+/// The parameter counts segments: the domain is `[0, n]` for `n` segments, and
+/// `t = k` is the `k`-th vertex. Between two whole numbers the curve moves in a
+/// straight line, at a speed equal to the length of that segment.
+///
+/// # Examples
+/// ```
+/// use mather::{curve::{ParametricCurve, Polyline}, Point};
+///
+/// let polyline = Polyline::new(vec![
+///     Point::new([0.0, 0.0]),
+///     Point::new([2.0, 0.0]),
+///     Point::new([2.0, 2.0]),
+/// ]);
+/// assert_eq!(polyline.point(1.5), Point::new([2.0, 1.0]));
+/// assert_eq!(polyline.point_by_arc_length(1.0), Point::new([1.0, 0.0]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polyline<const N: usize> {
+    vertices: Vec<Point<N>>,
+}
+
+impl<const N: usize> Polyline<N> {
+    /// Creates a polyline through the given vertices, in order.
+    ///
+    /// # Arguments
+    /// * `vertices` - The vertices of the polyline
+    ///
+    /// # Panics
+    /// Panics if there are fewer than 2 vertices.
+    pub fn new(vertices: Vec<Point<N>>) -> Self {
+        assert!(
+            vertices.len() >= 2,
+            "Polyline needs at least 2 vertices, got {}",
+            vertices.len()
+        );
+        Polyline { vertices }
+    }
+
+    /// Returns the vertices of the polyline.
+    pub fn vertices(&self) -> &[Point<N>] {
+        &self.vertices
+    }
+
+    /// Returns the number of straight segments, one less than the number of vertices.
+    pub fn segment_count(&self) -> usize {
+        self.vertices.len() - 1
+    }
+
+    /// Returns the length of the segment starting at vertex `index`.
+    fn segment_length(&self, index: usize) -> f64 {
+        self.vertices[index].distance(&self.vertices[index + 1])
+    }
+
+    /// Interpolates along the segment starting at vertex `index`.
+    fn lerp(&self, index: usize, fraction: f64) -> Point<N> {
+        self.vertices[index].lerp(&self.vertices[index + 1], fraction)
+    }
+
+    /// Returns the point at a given distance along the polyline from its first vertex.
+    ///
+    /// The distance is measured exactly from the segment lengths. Distances below
+    /// `0` or beyond the total length are clamped to the first and last vertices.
+    ///
+    /// # Arguments
+    /// * `s` - The distance along the polyline
+    pub fn point_by_arc_length(&self, s: f64) -> Point<N> {
+        let mut remaining = s.max(0.0);
+        for index in 0..self.segment_count() {
+            let length = self.segment_length(index);
+            if remaining <= length {
+                let fraction = if length == 0.0 {
+                    0.0
+                } else {
+                    remaining / length
+                };
+                return self.lerp(index, fraction);
+            }
+            remaining -= length;
+        }
+        self.vertices[self.vertices.len() - 1]
+    }
+}
+
+impl<const N: usize> ParametricCurve<N> for Polyline<N> {
+    /// `[0, n]` for `n` segments.
+    fn get_domain(&self) -> Domain {
+        Domain::new(0.0, self.segment_count() as f64)
+    }
+
+    /// Interpolates between the vertices at `floor(t)` and `ceil(t)`.
+    ///
+    /// # Panics
+    /// Panics if `t` is outside the domain.
+    fn point(&self, t: f64) -> Point<N> {
+        let segments = self.segment_count();
+        assert!(
+            (0.0..=segments as f64).contains(&t),
+            "t = {} is outside the Polyline domain [0, {}]",
+            t,
+            segments
+        );
+
+        let index = (t.floor() as usize).min(segments - 1);
+        self.lerp(index, t - index as f64)
+    }
+
+    /// The sum of the segment lengths, computed exactly.
+    fn arc_length(&self) -> f64 {
+        (0..self.segment_count())
+            .map(|index| self.segment_length(index))
+            .sum()
+    }
+
+    /// The same as [`Polyline::point_by_arc_length`].
+    fn arc_length_point(&self, s: f64) -> Point<N> {
+        self.point_by_arc_length(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn staircase() -> Polyline<2> {
+        Polyline::new(vec![
+            Point::new([0.0, 0.0]),
+            Point::new([4.0, 0.0]),
+            Point::new([4.0, 1.0]),
+            Point::new([7.0, 5.0]),
+        ])
+    }
+
+    #[test]
+    fn test_point_at_vertices() {
+        let polyline = staircase();
+
+        assert_eq!(polyline.get_domain(), Domain::new(0.0, 3.0));
+        assert_eq!(polyline.point(0.0), Point::new([0.0, 0.0]));
+        assert_eq!(polyline.point(2.0), Point::new([4.0, 1.0]));
+        assert_eq!(polyline.point(3.0), Point::new([7.0, 5.0]));
+    }
+
+    #[test]
+    fn test_point_interpolates_within_segment() {
+        let polyline = staircase();
+
+        assert_eq!(polyline.point(0.5), Point::new([2.0, 0.0]));
+        assert_eq!(polyline.point(1.25), Point::new([4.0, 0.25]));
+        assert_eq!(polyline.point(2.5), Point::new([5.5, 3.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the Polyline domain [0, 3]")]
+    fn test_point_outside_domain() {
+        staircase().point(3.5);
+    }
+
+    #[test]
+    fn test_arc_length() {
+        let polyline = staircase();
+
+        assert_eq!(polyline.arc_length(), 10.0);
+        assert_eq!(polyline.point_by_arc_length(2.0), Point::new([2.0, 0.0]));
+        assert_eq!(polyline.point_by_arc_length(4.5), Point::new([4.0, 0.5]));
+        assert_eq!(polyline.arc_length_point(7.5), Point::new([5.5, 3.0]));
+    }
+
+    #[test]
+    fn test_point_by_arc_length_clamps() {
+        let polyline = staircase();
+
+        assert_eq!(polyline.point_by_arc_length(-1.0), Point::new([0.0, 0.0]));
+        assert_eq!(polyline.point_by_arc_length(11.0), Point::new([7.0, 5.0]));
+    }
+
+    #[test]
+    fn test_repeated_vertex() {
+        let polyline = Polyline::new(vec![
+            Point::new([0.0]),
+            Point::new([0.0]),
+            Point::new([2.0]),
+        ]);
+
+        assert_eq!(polyline.arc_length(), 2.0);
+        assert_eq!(polyline.point_by_arc_length(1.0), Point::new([1.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 vertices")]
+    fn test_too_few_vertices() {
+        Polyline::<2>::new(vec![]);
+    }
+}
@@ -0,0 +1,49 @@
+use num_traits::NumCast;
+
+use crate::Domain;
+
+impl<T: NumCast + Copy> Domain<T> {
+    /// Converts the bounds of the domain to another numeric type.
+    ///
+    /// This is useful for moving between integer and float domains, for example
+    /// to sample an integer range with the `f64`-only methods.
+    ///
+    /// # Returns
+    /// * `Some(Domain<U>)` - The domain with both bounds converted
+    /// * `None` - If either bound can't be represented in `U`
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let pixels: Domain<i32> = Domain::new(0, 100);
+    /// let domain: Domain<f64> = pixels.cast().unwrap();
+    /// assert_eq!(domain.project(0.5), 50.0);
+    /// ```
+    pub fn cast<U: NumCast>(&self) -> Option<Domain<U>> {
+        Some(Domain::new(U::from(self.start)?, U::from(self.end)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast_integer_to_float() {
+        let domain: Domain<i32> = Domain::new(-3, 7);
+        assert_eq!(domain.cast::<f32>(), Some(Domain::new(-3.0, 7.0)));
+    }
+
+    #[test]
+    fn cast_float_to_integer_truncates() {
+        let domain = Domain::new(0.9, 10.5);
+        assert_eq!(domain.cast::<i32>(), Some(Domain::new(0, 10)));
+    }
+
+    #[test]
+    fn cast_out_of_range() {
+        let domain: Domain<i32> = Domain::new(-1, 300);
+        assert_eq!(domain.cast::<u8>(), None);
+    }
+}
@@ -1,10 +1,11 @@
-use crate::Domain;
+use crate::{Domain, DomainValue};
 
 /// Trait for types that can be clamped to a given domain.
 ///
 /// Implementing this trait allows a type to be restricted to the bounds
-/// of a specified domain using the `domain_clamp` method.
-pub trait DomainClamp {
+/// of a specified domain using the `domain_clamp` method. The type parameter
+/// is the bound type of the domain, which defaults to `f64`.
+pub trait DomainClamp<T = f64> {
     /// Clamps the value to be within the specified domain.
     ///
     /// # Arguments
@@ -12,10 +13,10 @@ pub trait DomainClamp {
     ///
     /// # Returns
     /// A new value of the same type that lies within the domain bounds
-    fn domain_clamp(&self, domain: &Domain) -> Self;
+    fn domain_clamp(&self, domain: &Domain<T>) -> Self;
 }
 
-impl Domain {
+impl<T: DomainValue> Domain<T> {
     /// Clamps a value to be within this domain.
     ///
     /// # Examples
@@ -27,16 +28,16 @@ impl Domain {
     /// assert_eq!(domain.clamp(5.0), 5.0);
     /// assert_eq!(domain.clamp(15.0), 10.0);
     /// ```
-    pub fn clamp<T>(&self, value: T) -> T
+    pub fn clamp<V>(&self, value: V) -> V
     where
-        T: DomainClamp,
+        V: DomainClamp<T>,
     {
         value.domain_clamp(self)
     }
 }
 
-impl DomainClamp for f64 {
-    fn domain_clamp(&self, domain: &Domain) -> f64 {
+impl<T: DomainValue> DomainClamp<T> for T {
+    fn domain_clamp(&self, domain: &Domain<T>) -> T {
         match *self {
             value if value < domain.start => domain.start,
             value if value > domain.end => domain.end,
@@ -45,8 +46,8 @@ impl DomainClamp for f64 {
     }
 }
 
-impl DomainClamp for Domain {
-    fn domain_clamp(&self, domain: &Domain) -> Domain {
+impl<T: DomainValue> DomainClamp<T> for Domain<T> {
+    fn domain_clamp(&self, domain: &Domain<T>) -> Domain<T> {
         Domain::new(
             self.start.domain_clamp(domain),
             self.end.domain_clamp(domain),
@@ -92,4 +93,21 @@ mod tests {
         assert_eq!(clamped.start, 5.0);
         assert_eq!(clamped.end, 10.0);
     }
+
+    #[test]
+    fn test_clamp_f32() {
+        let domain: Domain<f32> = Domain::new(0.0, 1.0);
+        assert_eq!(domain.clamp(-0.5), 0.0);
+        assert_eq!(domain.clamp(0.25), 0.25);
+        assert_eq!(domain.clamp(1.5), 1.0);
+    }
+
+    #[test]
+    fn test_clamp_i32() {
+        let domain: Domain<i32> = Domain::new(-10, 10);
+        assert_eq!(domain.clamp(-20), -10);
+        assert_eq!(domain.clamp(3), 3);
+        assert_eq!(domain.clamp(20), 10);
+        assert_eq!(domain.clamp(Domain::new(-20, 5)), Domain::new(-10, 5));
+    }
 }
@@ -1,31 +1,32 @@
-use crate::Domain;
+use crate::{Domain, DomainValue};
 
-impl Domain {
+impl<T: DomainValue> Domain<T> {
     /// Checks if a value is within this domain, inclusive.
     ///
     /// # Examples
     /// ```
-    /// use geomagic::data::Domain;
+    /// use mather::Domain;
     ///
     /// let domain = Domain::new(0.0, 10.0);
-    /// assert!(domain.has(5.0));
-    /// assert!(!domain.has(11.0));
+    /// assert!(domain.contains_value(5.0));
+    /// assert!(domain.contains_value(10.0));
+    /// assert!(!domain.contains_value(11.0));
     /// ```
-    pub fn contains_value(&self, value: f64) -> bool {
+    pub fn contains_value(&self, value: T) -> bool {
         value >= self.start && value <= self.end
     }
 
-    /// Checks if a value is within this domain, inclusive.
+    /// Checks if a value is strictly within this domain, excluding the bounds.
     ///
     /// # Examples
     /// ```
-    /// use geomagic::data::Domain;
+    /// use mather::Domain;
     ///
     /// let domain = Domain::new(0.0, 10.0);
-    /// assert!(domain.has(5.0));
-    /// assert!(!domain.has(11.0));
+    /// assert!(domain.strictly_contains_value(5.0));
+    /// assert!(!domain.strictly_contains_value(10.0));
     /// ```
-    pub fn strictly_contains_value(&self, value: f64) -> bool {
+    pub fn strictly_contains_value(&self, value: T) -> bool {
         value > self.start && value < self.end
     }
 
@@ -33,35 +34,68 @@ impl Domain {
     ///
     /// # Examples
     /// ```
-    /// use geomagic::data::Domain;
+    /// use mather::Domain;
     ///
     /// let domain1 = Domain::new(0.0, 10.0);
     /// let domain2 = Domain::new(2.0, 8.0);
-    /// assert!(domain1.contains(domain2));
+    /// assert!(domain1.contains_domain(domain2));
     /// ```
-    pub fn contains_domain(&self, other: Domain) -> bool {
+    pub fn contains_domain(&self, other: Domain<T>) -> bool {
         self.contains_value(other.start) && self.contains_value(other.end)
     }
 
-    /// Checks if this domain completely contains another domain.
+    /// Checks if this domain completely contains another domain, excluding the bounds.
     ///
     /// # Examples
     /// ```
-    /// use geomagic::data::Domain;
+    /// use mather::Domain;
     ///
     /// let domain1 = Domain::new(0.0, 10.0);
-    /// let domain2 = Domain::new(2.0, 8.0);
-    /// assert!(domain1.contains(domain2));
+    /// assert!(domain1.strictly_contains_domain(Domain::new(2.0, 8.0)));
+    /// assert!(!domain1.strictly_contains_domain(Domain::new(0.0, 8.0)));
     /// ```
-    pub fn strictly_contains_domain(&self, other: Domain) -> bool {
+    pub fn strictly_contains_domain(&self, other: Domain<T>) -> bool {
         self.strictly_contains_value(other.start) && self.strictly_contains_value(other.end)
     }
+
+    /// Checks if this domain shares at least one point with another domain.
+    ///
+    /// Unlike [`Domain::contains_domain`], a partial overlap is enough, and
+    /// domains that only touch at a boundary also overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.0, 10.0);
+    /// assert!(domain.overlaps(&Domain::new(5.0, 15.0)));
+    /// assert!(domain.overlaps(&Domain::new(10.0, 15.0)));
+    /// assert!(!domain.overlaps(&Domain::new(11.0, 15.0)));
+    /// ```
+    pub fn overlaps(&self, other: &Domain<T>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Checks if this domain shares no points with another domain.
+    ///
+    /// This is the negation of [`Domain::overlaps`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.0, 10.0);
+    /// assert!(domain.disjoint(&Domain::new(11.0, 15.0)));
+    /// assert!(!domain.disjoint(&Domain::new(10.0, 15.0)));
+    /// ```
+    pub fn disjoint(&self, other: &Domain<T>) -> bool {
+        !self.overlaps(other)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::f64::EPSILON;
 
     #[test]
     fn test_has() {
@@ -89,4 +123,60 @@ mod tests {
         let domain = Domain::new(-5.0, 5.0);
         assert_eq!(domain.length(), 10.0);
     }
+
+    #[test]
+    fn test_contains_value_f32() {
+        let domain: Domain<f32> = Domain::new(-1.0, 1.0);
+        assert!(domain.contains_value(0.5));
+        assert!(domain.contains_value(1.0));
+        assert!(!domain.strictly_contains_value(1.0));
+        assert!(!domain.contains_value(1.5));
+    }
+
+    #[test]
+    fn test_contains_value_i32() {
+        let domain: Domain<i32> = Domain::new(0, 10);
+        assert!(domain.contains_value(0));
+        assert!(domain.contains_value(10));
+        assert!(!domain.contains_value(-1));
+        assert!(domain.contains_domain(Domain::new(3, 4)));
+        assert!(!domain.strictly_contains_domain(Domain::new(0, 4)));
+    }
+
+    #[test]
+    fn test_overlaps_full_containment() {
+        let outer = Domain::new(0.0, 10.0);
+        let inner = Domain::new(2.0, 3.0);
+        assert!(outer.overlaps(&inner));
+        assert!(inner.overlaps(&outer));
+        assert!(!outer.disjoint(&inner));
+    }
+
+    #[test]
+    fn test_overlaps_partial() {
+        let a = Domain::new(0.0, 5.0);
+        let b = Domain::new(3.0, 8.0);
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(!a.disjoint(&b));
+    }
+
+    #[test]
+    fn test_overlaps_touching() {
+        let a = Domain::new(0.0, 5.0);
+        let b = Domain::new(5.0, 8.0);
+        assert!(a.overlaps(&b), "Touching domains share their boundary");
+        assert!(b.overlaps(&a));
+        assert!(!a.disjoint(&b));
+    }
+
+    #[test]
+    fn test_overlaps_disjoint() {
+        let a = Domain::new(0.0, 5.0);
+        let b = Domain::new(6.0, 8.0);
+        assert!(!a.overlaps(&b));
+        assert!(!b.overlaps(&a));
+        assert!(a.disjoint(&b));
+        assert!(Domain::new(0, 1).disjoint(&Domain::new(2, 3)));
+    }
 }
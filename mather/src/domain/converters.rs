@@ -1,4 +1,5 @@
 use crate::Domain;
+use std::fmt;
 use std::num::ParseFloatError;
 use std::str::FromStr;
 
@@ -39,16 +40,31 @@ impl FromStr for Domain {
             return Err(ConvertError::InvalidFormat);
         }
 
-        let start = parts[0]
-            .parse()
-            .map_err(|x| ConvertError::ParseFloatError(x))?;
-        let end = parts[1]
-            .parse()
-            .map_err(|x| ConvertError::ParseFloatError(x))?;
+        let start = parts[0].parse().map_err(ConvertError::ParseFloatError)?;
+        let end = parts[1].parse().map_err(ConvertError::ParseFloatError)?;
         Ok(Domain { start, end })
     }
 }
 
+impl fmt::Display for Domain {
+    /// Writes the domain in the format "(start,end)" accepted by `FromStr`.
+    ///
+    /// Values are written with the shortest representation that parses back to
+    /// the same `f64`, so `to_string` and `parse` round-trip losslessly.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.5, 10.0);
+    /// assert_eq!(domain.to_string(), "(0.5,10)");
+    /// assert_eq!(domain.to_string().parse::<Domain>().unwrap(), domain);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.start, self.end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,4 +99,37 @@ mod tests {
             Err(ConvertError::InvalidFormat)
         ));
     }
+
+    #[test]
+    fn test_display_format() {
+        assert_eq!(Domain::new(0.0, 10.0).to_string(), "(0,10)");
+        assert_eq!(Domain::new(-1.5, 2.25).to_string(), "(-1.5,2.25)");
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            -273.15,
+            0.1 + 0.2,
+            1.0 / 3.0,
+            1e-300,
+            6.02214076e23,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::MIN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+
+        for &start in &values {
+            for &end in &values {
+                let domain = Domain::new(start, end);
+                let parsed: Domain = domain.to_string().parse().unwrap();
+                assert_eq!(parsed, domain, "Failed to round-trip {}", domain);
+            }
+        }
+    }
 }
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use crate::Domain;
+
+impl Domain {
+    /// Formats the domain in bracket notation with a fixed number of decimal places.
+    ///
+    /// This is intended for human-readable output. Use `to_string`, which writes
+    /// the `"(start,end)"` format, when the string needs to be parsed back into a
+    /// Domain.
+    ///
+    /// # Arguments
+    /// * `decimal_places` - The number of digits to print after the decimal point
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.0, 10.0);
+    /// assert_eq!(domain.format(2), "[0.00, 10.00]");
+    /// ```
+    pub fn format(&self, decimal_places: usize) -> String {
+        format!(
+            "[{:.*}, {:.*}]",
+            decimal_places, self.start, decimal_places, self.end
+        )
+    }
+}
+
+/// Formats the domain in bracket notation using scientific notation.
+///
+/// The formatter precision, if given, is applied to both bounds.
+///
+/// # Examples
+/// ```
+/// use mather::Domain;
+///
+/// let domain = Domain::new(0.0, 10.0);
+/// assert_eq!(format!("{:.3e}", domain), "[0.000e0, 1.000e1]");
+/// assert_eq!(format!("{:e}", domain), "[0e0, 1e1]");
+/// ```
+impl fmt::LowerExp for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "[{:.*e}, {:.*e}]",
+                precision, self.start, precision, self.end
+            ),
+            None => write!(f, "[{:e}, {:e}]", self.start, self.end),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_decimal_places() {
+        let domain = Domain::new(0.0, 1.0);
+        assert_eq!(domain.format(3), "[0.000, 1.000]");
+        assert_eq!(domain.format(0), "[0, 1]");
+    }
+
+    #[test]
+    fn test_format_rounds() {
+        let domain = Domain::new(-1.23456, 9.87654);
+        assert_eq!(domain.format(2), "[-1.23, 9.88]");
+    }
+
+    #[test]
+    fn test_lower_exp() {
+        let domain = Domain::new(0.0, 10.0);
+        assert_eq!(format!("{:.3e}", domain), "[0.000e0, 1.000e1]");
+    }
+
+    #[test]
+    fn test_lower_exp_is_parseable() {
+        let domain = Domain::new(-0.00125, 42000.0);
+        let formatted = format!("{:e}", domain);
+
+        let bounds: Vec<f64> = formatted
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(", ")
+            .map(|part| part.parse().unwrap())
+            .collect();
+        assert_eq!(bounds, vec![domain.start, domain.end]);
+    }
+}
@@ -1,6 +1,8 @@
+use std::ops::RangeInclusive;
+
 use crate::Domain;
 
-/// Implements conversion from a tuple of (f64, f64) to Domain
+/// Implements conversion from a tuple of (start, end) to Domain
 ///
 /// # Arguments
 /// * `domain` - A tuple containing (start, end) values for the domain
@@ -12,11 +14,68 @@ use crate::Domain;
 /// assert_eq!(domain.start, 0.0);
 /// assert_eq!(domain.end, 1.0);
 /// ```
-impl From<(f64, f64)> for Domain {
-    fn from(domain: (f64, f64)) -> Self {
+impl<T> From<(T, T)> for Domain<T> {
+    fn from(domain: (T, T)) -> Self {
         Domain {
             start: domain.0,
             end: domain.1,
         }
     }
 }
+
+/// Implements conversion from an inclusive range to Domain
+///
+/// # Example
+/// ```
+/// use mather::Domain;
+/// let domain = Domain::from(0.0..=1.0);
+/// assert_eq!(domain, Domain::new(0.0, 1.0));
+/// ```
+impl<T> From<RangeInclusive<T>> for Domain<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Domain { start, end }
+    }
+}
+
+/// Implements conversion from Domain to an inclusive range
+///
+/// # Example
+/// ```
+/// use mather::Domain;
+/// use std::ops::RangeInclusive;
+/// let range: RangeInclusive<f64> = Domain::new(0.0, 1.0).into();
+/// assert_eq!(range, 0.0..=1.0);
+/// ```
+impl<T> From<Domain<T>> for RangeInclusive<T> {
+    fn from(domain: Domain<T>) -> Self {
+        domain.start..=domain.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_tuple() {
+        let domain = Domain::from((2.0, 3.0));
+        assert_eq!(domain, Domain::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_range_inclusive_round_trip() {
+        let domain = Domain::new(-1.5, 4.0);
+        let range: RangeInclusive<f64> = domain.into();
+        assert_eq!(range, -1.5..=4.0);
+        assert_eq!(Domain::from(range), domain);
+    }
+
+    #[test]
+    fn test_integer_conversions() {
+        let domain: Domain<i32> = Domain::from(1..=5);
+        assert_eq!(domain, Domain::new(1, 5));
+        assert_eq!(RangeInclusive::from(domain), 1..=5);
+        assert_eq!(Domain::from((2_u8, 9_u8)), Domain::new(2, 9));
+    }
+}
@@ -1,16 +1,16 @@
-use crate::Domain;
+use crate::{Domain, DomainValue};
 
-impl Domain {
+impl<T: DomainValue> Domain<T> {
     /// Returns the length of the domain.
     ///
     /// # Examples
     /// ```
-    /// use geomagic::data::Domain;
+    /// use mather::Domain;
     ///
     /// let domain : Domain = Domain::new(0.0, 10.0);
     /// assert_eq!(domain.length(), 10.0);
     /// ```
-    pub fn length(&self) -> f64 {
+    pub fn length(&self) -> T {
         self.end - self.start
     }
 
@@ -18,7 +18,7 @@ impl Domain {
     ///
     /// # Examples
     /// ```
-    /// use geomagic::data::Domain;
+    /// use mather::Domain;
     ///
     /// let domain : Domain = Domain::new(0.0, 10.0);
     /// domain.is_increasing();
@@ -27,17 +27,17 @@ impl Domain {
         self.end > self.start
     }
 
-    /// Returns if the domain is increasing.
+    /// Returns if the domain is decreasing.
     ///
     /// # Examples
     /// ```
-    /// use geomagic::data::Domain;
+    /// use mather::Domain;
     ///
     /// let domain : Domain = Domain::new(10.0, 0.0);
     /// domain.is_decreasing();
     /// ```
     pub fn is_decreasing(&self) -> bool {
-        self.start < self.end
+        self.start > self.end
     }
 }
 
@@ -72,4 +72,12 @@ mod tests {
         let domain = Domain::new(0.0, 0.0);
         assert!(!domain.is_decreasing());
     }
+
+    #[test]
+    fn test_identity_with_integers() {
+        let domain: Domain<i32> = Domain::new(-3, 4);
+        assert_eq!(domain.length(), 7);
+        assert!(domain.is_increasing());
+        assert!(!domain.is_decreasing());
+    }
 }
@@ -0,0 +1,88 @@
+use crate::{Domain, DomainValue};
+
+impl<T: DomainValue> Domain<T> {
+    /// Returns the range covered by both this domain and another.
+    ///
+    /// Both domains are expected to be increasing. Domains that only touch at a
+    /// boundary intersect in a zero-length domain at that point.
+    ///
+    /// # Arguments
+    /// * `other` - The domain to intersect with
+    ///
+    /// # Returns
+    /// * `Some(Domain)` - The overlapping range
+    /// * `None` - If the domains are disjoint
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let a = Domain::new(0.0, 5.0);
+    /// let b = Domain::new(3.0, 8.0);
+    /// assert_eq!(a.intersection(&b), Some(Domain::new(3.0, 5.0)));
+    /// assert_eq!(a.intersection(&Domain::new(6.0, 7.0)), None);
+    /// ```
+    pub fn intersection(&self, other: &Domain<T>) -> Option<Domain<T>> {
+        let start = if other.start > self.start {
+            other.start
+        } else {
+            self.start
+        };
+        let end = if other.end < self.end {
+            other.end
+        } else {
+            self.end
+        };
+        if start <= end {
+            Some(Domain::new(start, end))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_full_overlap() {
+        let outer = Domain::new(0.0, 10.0);
+        let inner = Domain::new(2.0, 4.0);
+        assert_eq!(outer.intersection(&inner), Some(inner));
+        assert_eq!(inner.intersection(&outer), Some(inner));
+    }
+
+    #[test]
+    fn intersection_partial_overlap() {
+        let a = Domain::new(1.0, 3.0);
+        let b = Domain::new(2.0, 4.0);
+        assert_eq!(a.intersection(&b), Some(Domain::new(2.0, 3.0)));
+        assert_eq!(b.intersection(&a), Some(Domain::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn intersection_touching_boundary() {
+        let a = Domain::new(1.0, 2.0);
+        let b = Domain::new(2.0, 3.0);
+        assert_eq!(a.intersection(&b), Some(Domain::new(2.0, 2.0)));
+    }
+
+    #[test]
+    fn intersection_disjoint() {
+        let a = Domain::new(1.0, 2.0);
+        let b = Domain::new(3.0, 4.0);
+        assert_eq!(a.intersection(&b), None);
+        assert_eq!(b.intersection(&a), None);
+    }
+
+    #[test]
+    fn intersection_integers() {
+        let a: Domain<i32> = Domain::new(0, 10);
+        assert_eq!(
+            a.intersection(&Domain::new(5, 20)),
+            Some(Domain::new(5, 10))
+        );
+        assert_eq!(a.intersection(&Domain::new(11, 20)), None);
+    }
+}
@@ -1,11 +1,35 @@
+use std::ops::{Add, Sub};
+
+#[cfg(feature = "num-traits")]
+mod cast;
 mod clamp;
+mod containment;
 mod converters;
 mod default;
+mod format;
 mod from;
+mod identity;
+mod intersection;
 mod operators;
 mod projection;
+mod sampling;
+mod scale;
+mod split;
+
+/// Numeric types that can be used as the bounds of a [`Domain`].
+///
+/// This is implemented for every type with the required arithmetic and
+/// ordering, which includes all of the primitive integer and float types.
+pub trait DomainValue: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> {}
 
-/// Represents a continuous domain between two f64 values.
+impl<T> DomainValue for T where T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> {}
+
+/// Represents a continuous domain between two values.
+///
+/// The bounds default to `f64`, so `Domain` on its own is `Domain<f64>`. Other
+/// numeric types such as `f32` or `i32` can be used for the methods that only
+/// need basic arithmetic and ordering. Projection and remapping need `f32` or
+/// `f64`, while sampling, scaling, formatting and parsing are specific to `f64`.
 ///
 /// # Examples
 /// ```
@@ -14,31 +38,74 @@ mod projection;
 /// let domain : Domain = Domain::new(0.0, 1.0);
 /// assert_eq!(domain.start, 0.0);
 /// assert_eq!(domain.end, 1.0);
+///
+/// let integers: Domain<i32> = Domain::new(0, 10);
+/// assert!(integers.contains_value(5));
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Domain {
+pub struct Domain<T = f64> {
     /// The starting value of the domain
-    pub start: f64,
+    pub start: T,
     /// The ending value of the domain
-    pub end: f64,
+    pub end: T,
 }
 
-impl Domain {
+impl<T> Domain<T> {
     /// Creates a new Domain with the given start and end values.
     ///
     /// # Arguments
     /// * `start` - The starting value of the range
     /// * `end` - The ending value of the range
-    pub fn new(start: f64, end: f64) -> Self {
+    pub fn new(start: T, end: T) -> Self {
         Domain { start, end }
     }
+}
 
+impl Domain {
     /// Creates a new Unit Domain [0,1]
     pub fn unit_domain() -> Self {
         Domain::new(0.0, 1.0)
     }
 }
 
+impl<T: DomainValue> Domain<T> {
+    /// Creates a new increasing Domain from two bounds given in any order.
+    ///
+    /// Unlike [`Domain::new`], the bounds are swapped when `min > max`, so the
+    /// result always satisfies `start <= end`. This is useful when the bounds
+    /// come from external data where their order is not guaranteed.
+    ///
+    /// # Arguments
+    /// * `min` - The expected lower bound of the range
+    /// * `max` - The expected upper bound of the range
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// assert_eq!(Domain::from_bounds(10.0, 1.0), Domain::new(1.0, 10.0));
+    /// ```
+    pub fn from_bounds(min: T, max: T) -> Self {
+        if min <= max {
+            Domain::new(min, max)
+        } else {
+            Domain::new(max, min)
+        }
+    }
+
+    /// Returns the `(start, end)` bounds of the domain as a tuple.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// assert_eq!(Domain::new(1.0, 2.0).to_bounds(), (1.0, 2.0));
+    /// ```
+    pub fn to_bounds(&self) -> (T, T) {
+        (self.start, self.end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +116,28 @@ mod tests {
         assert_eq!(domain.start, 1.0);
         assert_eq!(domain.end, 2.0);
     }
+
+    #[test]
+    fn from_bounds_keeps_ordered_bounds() {
+        assert_eq!(Domain::from_bounds(1.0, 10.0), Domain::new(1.0, 10.0));
+    }
+
+    #[test]
+    fn from_bounds_swaps_reversed_bounds() {
+        let domain = Domain::from_bounds(10.0, 1.0);
+        assert_eq!(domain, Domain::new(1.0, 10.0));
+        assert_ne!(domain, Domain::new(10.0, 1.0));
+    }
+
+    #[test]
+    fn to_bounds_returns_start_and_end() {
+        assert_eq!(Domain::new(-3.0, 4.0).to_bounds(), (-3.0, 4.0));
+    }
+
+    #[test]
+    fn from_bounds_with_integers() {
+        let domain: Domain<i32> = Domain::from_bounds(7, -2);
+        assert_eq!(domain, Domain::new(-2, 7));
+        assert_eq!(domain.to_bounds(), (-2, 7));
+    }
 }
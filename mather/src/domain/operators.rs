@@ -8,6 +8,13 @@
 //! and maximum end value of both domains. This effectively creates a new domain
 //! that spans the entire range covered by both input domains.
 //!
+//! # Multiplication
+//! The `*` operator intersects two domains, returning the range covered by both
+//! or `None` if they are disjoint. See [`Domain::intersection`].
+//!
+//! Multiplying a domain by an `f64` scales its length about the start point
+//! instead. See [`Domain::scale`].
+//!
 //! # Examples
 //! ```
 //! use mather::Domain;
@@ -17,35 +24,85 @@
 //! let combined = domain1 + domain2;
 //! assert_eq!(combined.start, 1.0);
 //! assert_eq!(combined.end, 4.0);
+//!
+//! let overlap = domain1 * domain2;
+//! assert_eq!(overlap, Some(Domain::new(2.0, 3.0)));
+//!
+//! let half = domain1 * 0.5;
+//! assert_eq!(half, Domain::new(1.0, 2.0));
 //! ```
 
-use super::Domain;
-use std::ops::Add;
+use super::{Domain, DomainValue};
+use std::ops::{Add, Mul};
+
+/// The smaller of two bounds, ignoring a NaN bound like [`f64::min`].
+#[allow(clippy::eq_op)]
+fn lower<T: DomainValue>(a: T, b: T) -> T {
+    if a != a || b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The larger of two bounds, ignoring a NaN bound like [`f64::max`].
+#[allow(clippy::eq_op)]
+fn upper<T: DomainValue>(a: T, b: T) -> T {
+    if a != a || b > a {
+        b
+    } else {
+        a
+    }
+}
 
 macro_rules! impl_domain_operators {
     ($(($lhs:ty, $rhs:ty)), *) => {
         $(
-            impl Add<$rhs> for $lhs {
-                type Output = Domain;
+            impl<T: DomainValue> Add<$rhs> for $lhs {
+                type Output = Domain<T>;
 
                 fn add(self, other: $rhs) -> Self::Output {
                     Domain {
-                        start: self.start.min(other.start),
-                        end: self.end.max(other.end),
+                        start: lower(self.start, other.start),
+                        end: upper(self.end, other.end),
                     }
                 }
             }
+
+            impl<T: DomainValue> Mul<$rhs> for $lhs {
+                type Output = Option<Domain<T>>;
+
+                fn mul(self, other: $rhs) -> Self::Output {
+                    self.intersection(&other)
+                }
+            }
         )*
     };
 }
 
 impl_domain_operators!(
-    (Domain, Domain),
-    (Domain, &Domain),
-    (&Domain, Domain),
-    (&Domain, &Domain)
+    (Domain<T>, Domain<T>),
+    (Domain<T>, &Domain<T>),
+    (&Domain<T>, Domain<T>),
+    (&Domain<T>, &Domain<T>)
 );
 
+impl Mul<f64> for Domain {
+    type Output = Domain;
+
+    fn mul(self, factor: f64) -> Self::Output {
+        self.scale(factor)
+    }
+}
+
+impl Mul<f64> for &Domain {
+    type Output = Domain;
+
+    fn mul(self, factor: f64) -> Self::Output {
+        self.scale(factor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,6 +171,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn add_with_references() {
         let domain1 = Domain::new(1.0, 3.0);
         let domain2 = Domain::new(2.0, 4.0);
@@ -121,4 +179,52 @@ mod tests {
         assert_eq!(result.start, 1.0);
         assert_eq!(result.end, 4.0);
     }
+
+    #[test]
+    fn add_integer_ranges() {
+        let domain1: Domain<i32> = Domain::new(1, 3);
+        let domain2: Domain<i32> = Domain::new(2, 6);
+        assert_eq!(domain1 + domain2, Domain::new(1, 6));
+    }
+
+    #[test]
+    fn add_ignores_nan_bounds() {
+        let domain1 = Domain::new(f64::NAN, 3.0);
+        let domain2 = Domain::new(2.0, f64::NAN);
+        assert_eq!(domain1 + domain2, Domain::new(2.0, 3.0));
+        assert_eq!(domain2 + domain1, Domain::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn mul_overlapping_ranges() {
+        let domain1 = Domain::new(1.0, 3.0);
+        let domain2 = Domain::new(2.0, 4.0);
+        assert_eq!(domain1 * domain2, Some(Domain::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn mul_disjoint_ranges() {
+        let domain1 = Domain::new(1.0, 2.0);
+        let domain2 = Domain::new(3.0, 4.0);
+        assert_eq!(domain1 * domain2, None);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn mul_with_references() {
+        let domain1 = Domain::new(0.0, 5.0);
+        let domain2 = Domain::new(5.0, 6.0);
+        assert_eq!(&domain1 * &domain2, Some(Domain::new(5.0, 5.0)));
+        assert_eq!(domain1 * &domain2, Some(Domain::new(5.0, 5.0)));
+        assert_eq!(&domain1 * domain2, Some(Domain::new(5.0, 5.0)));
+    }
+
+    #[test]
+    fn mul_by_scalar() {
+        let domain = Domain::new(1.0, 3.0);
+        assert_eq!(domain * 2.0, Domain::new(1.0, 5.0));
+        assert_eq!(domain * 0.5, Domain::new(1.0, 2.0));
+        assert_eq!(domain * 0.0, Domain::new(1.0, 1.0));
+        assert_eq!(&domain * 2.0, Domain::new(1.0, 5.0));
+    }
 }
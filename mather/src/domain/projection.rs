@@ -1,6 +1,23 @@
 use crate::Domain;
 
-impl Domain {
+use self::float::Float;
+
+mod float {
+    use std::ops::{Div, Mul};
+
+    use crate::DomainValue;
+
+    /// Floating point bounds, which project without rounding.
+    ///
+    /// This lives in a private module so it cannot be implemented for the
+    /// integer types, where projecting by a fraction is meaningless.
+    pub trait Float: DomainValue + Mul<Output = Self> + Div<Output = Self> {}
+
+    impl Float for f32 {}
+    impl Float for f64 {}
+}
+
+impl<T: Float> Domain<T> {
     /// Projects a value from [0,1] to this domain.
     ///
     /// # Examples
@@ -10,12 +27,12 @@ impl Domain {
     /// let domain : Domain = Domain::new(0.0, 10.0);
     /// assert_eq!(domain.project(0.5), 5.0);
     /// ```
-    pub fn project(&self, value: f64) -> f64 {
+    pub fn project(&self, value: T) -> T {
         let domain = self.end - self.start;
         value * domain + self.start
     }
 
-    pub fn remap_value_from(&self, other: Domain, value: f64) -> f64 {
+    pub fn remap_value_from(&self, other: Domain<T>, value: T) -> T {
         // [a,b] -> [A,B]
         // let u be some parameter in [a,b]
         // (u - a) is the distance from a;
@@ -24,7 +41,7 @@ impl Domain {
         other.start + (other.end - other.start) * (value - self.start) / (self.end - self.start)
     }
 
-    pub fn remap_value_to(&self, other: Domain, value: f64) -> f64 {
+    pub fn remap_value_to(&self, other: Domain<T>, value: T) -> T {
         // [a,b] -> [A,B]
         // let u be some parameter in [a,b]
         // (u - a) is the distance from a;
@@ -33,15 +50,15 @@ impl Domain {
         self.start + (self.end - self.start) * (value - other.start) / (other.end - other.start)
     }
 
-    pub fn remap_domain_from(&self, other: Domain, value: Domain) -> Domain {
-        let start: f64 = self.remap_value_from(other, value.start);
-        let end: f64 = self.remap_value_from(other, value.end);
+    pub fn remap_domain_from(&self, other: Domain<T>, value: Domain<T>) -> Domain<T> {
+        let start: T = self.remap_value_from(other, value.start);
+        let end: T = self.remap_value_from(other, value.end);
         Domain { start, end }
     }
 
-    pub fn remap_domain_to(&self, other: Domain, value: Domain) -> Domain {
-        let start: f64 = self.remap_value_to(other, value.start);
-        let end: f64 = self.remap_value_to(other, value.end);
+    pub fn remap_domain_to(&self, other: Domain<T>, value: Domain<T>) -> Domain<T> {
+        let start: T = self.remap_value_to(other, value.start);
+        let end: T = self.remap_value_to(other, value.end);
         Domain { start, end }
     }
 
@@ -54,7 +71,7 @@ impl Domain {
     /// let domain : Domain = Domain::new(0.0, 10.0);
     /// assert_eq!(domain.map(5.0), 0.5);
     /// ```
-    pub fn map(&self, value: f64) -> f64 {
+    pub fn map(&self, value: T) -> T {
         let domain = self.end - self.start;
         (value - self.start) / domain
     }
@@ -63,7 +80,6 @@ impl Domain {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::f64::EPSILON;
 
     #[test]
     fn test_out() {
@@ -75,9 +91,18 @@ mod tests {
 
     #[test]
     fn test_into() {
-        let domain = Domain::new(0.0, 10.0);
-        assert!((domain.map(0.0) - 0.0).abs() < EPSILON);
-        assert!((domain.map(5.0) - 0.5).abs() < EPSILON);
-        assert!((domain.map(10.0) - 1.0).abs() < EPSILON);
+        let domain: Domain = Domain::new(0.0, 10.0);
+        assert!((domain.map(0.0) - 0.0).abs() < f64::EPSILON);
+        assert!((domain.map(5.0) - 0.5).abs() < f64::EPSILON);
+        assert!((domain.map(10.0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_f32() {
+        let domain: Domain<f32> = Domain::new(2.0, 4.0);
+        assert_eq!(domain.project(0.0), 2.0);
+        assert_eq!(domain.project(0.25), 2.5);
+        assert_eq!(domain.project(1.0), 4.0);
+        assert_eq!(domain.map(3.0), 0.5);
     }
 }
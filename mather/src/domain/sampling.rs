@@ -0,0 +1,155 @@
+use crate::Domain;
+
+impl Domain {
+    /// Returns `n` evenly spaced values from `start` to `end` inclusive.
+    ///
+    /// The i-th value is `project(i / (n - 1))`. A count of 1 yields only
+    /// `start`, and a count of 0 yields nothing.
+    ///
+    /// # Arguments
+    /// * `n` - The number of values to yield
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.0, 1.0);
+    /// let samples: Vec<f64> = domain.sample_n(5).collect();
+    /// assert_eq!(samples, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn sample_n(&self, n: usize) -> impl Iterator<Item = f64> {
+        let domain = *self;
+        let steps = n.saturating_sub(1).max(1) as f64;
+        (0..n).map(move |i| domain.project(i as f64 / steps))
+    }
+
+    /// Returns `n` evenly spaced values strictly between `start` and `end`.
+    ///
+    /// The domain is divided into `n + 1` equal steps and the interior points
+    /// are yielded, so neither endpoint is included. A count of 0 yields nothing.
+    ///
+    /// # Arguments
+    /// * `n` - The number of values to yield
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.0, 1.0);
+    /// let samples: Vec<f64> = domain.sample_n_open(3).collect();
+    /// assert_eq!(samples, vec![0.25, 0.5, 0.75]);
+    /// ```
+    pub fn sample_n_open(&self, n: usize) -> impl Iterator<Item = f64> {
+        let domain = *self;
+        let steps = (n + 1) as f64;
+        (1..=n).map(move |i| domain.project(i as f64 / steps))
+    }
+
+    /// Pairs evenly spaced samples of this domain with the items of another iterator.
+    ///
+    /// The domain is sampled at `count` evenly spaced values from `start` to `end`
+    /// inclusive. Iteration stops after `count` pairs or when `other` runs out,
+    /// whichever comes first. A `count` of 1 samples only `start`.
+    ///
+    /// # Arguments
+    /// * `other` - The iterator whose items are paired with the samples
+    /// * `count` - The number of samples to take from the domain
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.0, 1.0);
+    /// let pairs: Vec<(f64, &str)> = domain.zip_iter(["a", "b", "c"].into_iter(), 3).collect();
+    /// assert_eq!(pairs, vec![(0.0, "a"), (0.5, "b"), (1.0, "c")]);
+    /// ```
+    pub fn zip_iter<I: Iterator>(
+        &self,
+        other: I,
+        count: usize,
+    ) -> impl Iterator<Item = (f64, I::Item)> {
+        self.sample_n(count).zip(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_n_endpoints_and_step() {
+        let domain = Domain::new(2.0, 12.0);
+        let samples: Vec<f64> = domain.sample_n(6).collect();
+
+        assert_eq!(samples.len(), 6);
+        assert_eq!(samples.first(), Some(&2.0));
+        assert_eq!(samples.last(), Some(&12.0));
+        for pair in samples.windows(2) {
+            assert!((pair[1] - pair[0] - 2.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sample_n_small_counts() {
+        let domain = Domain::new(2.0, 4.0);
+        assert_eq!(domain.sample_n(0).count(), 0);
+        assert_eq!(domain.sample_n(1).collect::<Vec<_>>(), vec![2.0]);
+        assert_eq!(domain.sample_n(2).collect::<Vec<_>>(), vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_sample_n_decreasing_domain() {
+        let domain = Domain::new(1.0, 0.0);
+        let samples: Vec<f64> = domain.sample_n(3).collect();
+        assert_eq!(samples, vec![1.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_sample_n_open_excludes_endpoints() {
+        let domain = Domain::new(0.0, 10.0);
+        let samples: Vec<f64> = domain.sample_n_open(4).collect();
+
+        assert_eq!(samples, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(domain.sample_n_open(1).collect::<Vec<_>>(), vec![5.0]);
+        assert_eq!(domain.sample_n_open(0).count(), 0);
+    }
+
+    #[test]
+    fn test_zip_iter_pairs_values_with_labels() {
+        let domain = Domain::new(0.0, 1.0);
+        let labels = vec!["a", "b", "c", "d", "e"];
+
+        let pairs: Vec<(f64, &str)> = domain.zip_iter(labels.into_iter(), 5).collect();
+
+        assert_eq!(
+            pairs,
+            vec![(0.0, "a"), (0.25, "b"), (0.5, "c"), (0.75, "d"), (1.0, "e")]
+        );
+    }
+
+    #[test]
+    fn test_zip_iter_stops_when_other_is_exhausted() {
+        let domain = Domain::new(0.0, 10.0);
+
+        let pairs: Vec<(f64, i32)> = domain.zip_iter([1, 2].into_iter(), 5).collect();
+
+        assert_eq!(pairs, vec![(0.0, 1), (2.5, 2)]);
+    }
+
+    #[test]
+    fn test_zip_iter_stops_at_count() {
+        let domain = Domain::new(0.0, 10.0);
+
+        let pairs: Vec<(f64, i32)> = domain.zip_iter(0.., 3).collect();
+
+        assert_eq!(pairs, vec![(0.0, 0), (5.0, 1), (10.0, 2)]);
+    }
+
+    #[test]
+    fn test_zip_iter_small_counts() {
+        let domain = Domain::new(2.0, 4.0);
+
+        assert_eq!(domain.zip_iter(0.., 0).count(), 0);
+        assert_eq!(domain.zip_iter(0.., 1).collect::<Vec<_>>(), vec![(2.0, 0)]);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::Domain;
+
+impl Domain {
+    /// Scales the length of the domain about its start point.
+    ///
+    /// The start is kept fixed and the end is moved so that the new length is
+    /// `length() * factor`. This is the same as `domain * factor`.
+    ///
+    /// # Arguments
+    /// * `factor` - The multiplier applied to the length of the domain
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(2.0, 6.0);
+    /// assert_eq!(domain.scale(0.5), Domain::new(2.0, 4.0));
+    /// ```
+    pub fn scale(&self, factor: f64) -> Domain {
+        Domain::new(self.start, self.start + self.length() * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_doubles_length() {
+        let domain = Domain::new(1.0, 4.0);
+        let scaled = domain.scale(2.0);
+        assert_eq!(scaled, Domain::new(1.0, 7.0));
+        assert_eq!(scaled.length(), 2.0 * domain.length());
+    }
+
+    #[test]
+    fn scale_halves_length() {
+        let domain = Domain::new(0.0, 1.0);
+        assert_eq!(domain.scale(0.5), Domain::new(0.0, 0.5));
+    }
+
+    #[test]
+    fn scale_by_zero() {
+        let domain = Domain::new(3.0, 8.0);
+        let scaled = domain.scale(0.0);
+        assert_eq!(scaled, Domain::new(3.0, 3.0));
+        assert_eq!(scaled.length(), 0.0);
+    }
+
+    #[test]
+    fn scale_decreasing_domain() {
+        let domain = Domain::new(10.0, 6.0);
+        assert_eq!(domain.scale(0.5), Domain::new(10.0, 8.0));
+    }
+}
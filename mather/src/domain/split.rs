@@ -0,0 +1,157 @@
+use crate::{Domain, DomainValue};
+
+impl<T: DomainValue> Domain<T> {
+    /// Splits the domain into two sub-domains at the given value.
+    ///
+    /// The first half runs from `start` to `value` and the second from `value`
+    /// to `end`, so together they cover the original domain with no gap or
+    /// overlap. The value must lie strictly inside the domain.
+    ///
+    /// # Arguments
+    /// * `value` - The point to split at
+    ///
+    /// # Returns
+    /// * `Some((Domain, Domain))` - The two halves, in order from `start` to `end`
+    /// * `None` - If `value` is on a boundary or outside the domain
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let domain = Domain::new(0.0, 10.0);
+    /// assert_eq!(
+    ///     domain.split_at(4.0),
+    ///     Some((Domain::new(0.0, 4.0), Domain::new(4.0, 10.0)))
+    /// );
+    /// assert_eq!(domain.split_at(10.0), None);
+    /// ```
+    pub fn split_at(&self, value: T) -> Option<(Domain<T>, Domain<T>)> {
+        let (min, max) = if self.start <= self.end {
+            (self.start, self.end)
+        } else {
+            (self.end, self.start)
+        };
+        if value > min && value < max {
+            Some((Domain::new(self.start, value), Domain::new(value, self.end)))
+        } else {
+            None
+        }
+    }
+}
+
+impl Domain {
+    /// Partitions the domain into `n` equal sub-domains.
+    ///
+    /// Each boundary is computed directly from `start` rather than by adding
+    /// up step sizes, so rounding errors don't accumulate. Adjacent sub-domains
+    /// share their boundary exactly, the first begins at `start`, and the last
+    /// ends at `end`.
+    ///
+    /// # Arguments
+    /// * `n` - The number of sub-domains to create
+    ///
+    /// # Returns
+    /// The sub-domains in order from `start` to `end`, or an empty vector if `n == 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Domain;
+    ///
+    /// let parts = Domain::new(0.0, 1.0).subdivide(4);
+    /// assert_eq!(parts[1], Domain::new(0.25, 0.5));
+    /// assert_eq!(parts[3].end, 1.0);
+    /// ```
+    pub fn subdivide(&self, n: usize) -> Vec<Domain> {
+        let boundary = |i: usize| {
+            if i == n {
+                self.end
+            } else {
+                self.project(i as f64 / n as f64)
+            }
+        };
+
+        (0..n)
+            .map(|i| Domain::new(boundary(i), boundary(i + 1)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_at_inside() {
+        let domain = Domain::new(-2.0, 6.0);
+        let (first, second) = domain.split_at(1.0).unwrap();
+
+        assert_eq!(first, Domain::new(-2.0, 1.0));
+        assert_eq!(second, Domain::new(1.0, 6.0));
+        assert_eq!(first.end, second.start, "Halves should meet exactly");
+        assert_eq!(first + second, domain, "Halves should cover the domain");
+    }
+
+    #[test]
+    fn split_at_decreasing_domain() {
+        let domain = Domain::new(10.0, 0.0);
+        assert_eq!(
+            domain.split_at(3.0),
+            Some((Domain::new(10.0, 3.0), Domain::new(3.0, 0.0)))
+        );
+    }
+
+    #[test]
+    fn split_at_boundary() {
+        let domain = Domain::new(0.0, 10.0);
+        assert_eq!(domain.split_at(0.0), None);
+        assert_eq!(domain.split_at(10.0), None);
+    }
+
+    #[test]
+    fn split_at_outside() {
+        let domain = Domain::new(0.0, 10.0);
+        assert_eq!(domain.split_at(-1.0), None);
+        assert_eq!(domain.split_at(11.0), None);
+        assert_eq!(domain.split_at(f64::NAN), None);
+        assert_eq!(Domain::new(5.0, 5.0).split_at(5.0), None);
+    }
+
+    #[test]
+    fn subdivide_covers_domain() {
+        let domain = Domain::new(0.1, 0.7);
+        let parts = domain.subdivide(7);
+
+        assert_eq!(parts.len(), 7);
+        assert_eq!(parts.first().unwrap().start, domain.start);
+        assert_eq!(parts.last().unwrap().end, domain.end);
+        for pair in parts.windows(2) {
+            assert_eq!(
+                pair[0].end, pair[1].start,
+                "Neighbours should share endpoints"
+            );
+        }
+        let union = parts.iter().fold(parts[0], |acc, part| acc + part);
+        assert_eq!(union, domain);
+    }
+
+    #[test]
+    fn subdivide_equal_lengths() {
+        let parts = Domain::new(-4.0, 4.0).subdivide(4);
+        assert_eq!(
+            parts,
+            vec![
+                Domain::new(-4.0, -2.0),
+                Domain::new(-2.0, 0.0),
+                Domain::new(0.0, 2.0),
+                Domain::new(2.0, 4.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn subdivide_small_counts() {
+        let domain = Domain::new(1.0, 2.0);
+        assert!(domain.subdivide(0).is_empty());
+        assert_eq!(domain.subdivide(1), vec![domain]);
+    }
+}
@@ -1,3 +1,20 @@
+mod operators;
+mod vector;
+
+/// A point in N-dimensional space.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Point<const N: usize> {
     coords: [f64; N],
 }
+
+impl<const N: usize> Point<N> {
+    /// Creates a new point from its coordinates.
+    pub fn new(coords: [f64; N]) -> Self {
+        Point { coords }
+    }
+
+    /// Returns the coordinates of the point.
+    pub fn coords(&self) -> &[f64; N] {
+        &self.coords
+    }
+}
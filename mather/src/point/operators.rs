@@ -0,0 +1,92 @@
+//! Operator implementations for the Point type.
+//!
+//! Points are treated as vectors from the origin: `+` and `-` work component by
+//! component, `*` scales every coordinate by an `f64`, and unary `-` flips the
+//! sign of every coordinate.
+//!
+//! # Examples
+//! ```
+//! use mather::Point;
+//!
+//! let a = Point::new([1.0, 2.0]);
+//! let b = Point::new([3.0, 5.0]);
+//! assert_eq!(a + b, Point::new([4.0, 7.0]));
+//! assert_eq!(b - a, Point::new([2.0, 3.0]));
+//! assert_eq!(a * 2.0, Point::new([2.0, 4.0]));
+//! assert_eq!(-a, Point::new([-1.0, -2.0]));
+//! ```
+
+use super::Point;
+use std::ops::{Add, Mul, Neg, Sub};
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, other: Point<N>) -> Self::Output {
+        Point::new(std::array::from_fn(|i| self.coords[i] + other.coords[i]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, other: Point<N>) -> Self::Output {
+        Point::new(std::array::from_fn(|i| self.coords[i] - other.coords[i]))
+    }
+}
+
+impl<const N: usize> Mul<f64> for Point<N> {
+    type Output = Point<N>;
+
+    fn mul(self, scalar: f64) -> Self::Output {
+        Point::new(self.coords.map(|coord| coord * scalar))
+    }
+}
+
+impl<const N: usize> Mul<Point<N>> for f64 {
+    type Output = Point<N>;
+
+    fn mul(self, point: Point<N>) -> Self::Output {
+        point * self
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Self::Output {
+        Point::new(self.coords.map(|coord| -coord))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_sub() {
+        let a = Point::new([1.0, -2.0, 3.0]);
+        let b = Point::new([0.5, 4.0, -1.0]);
+
+        assert_eq!(a + b, Point::new([1.5, 2.0, 2.0]));
+        assert_eq!(a - b, Point::new([0.5, -6.0, 4.0]));
+        assert_eq!(a + b - b, a);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let a = Point::new([1.0, -2.0]);
+
+        assert_eq!(a * 3.0, Point::new([3.0, -6.0]));
+        assert_eq!(3.0 * a, a * 3.0);
+        assert_eq!(a * 0.0, Point::new([0.0, -0.0]));
+    }
+
+    #[test]
+    fn test_neg() {
+        let a = Point::new([1.0, -2.0]);
+
+        assert_eq!(-a, Point::new([-1.0, 2.0]));
+        assert_eq!(a + -a, Point::new([0.0, 0.0]));
+    }
+}
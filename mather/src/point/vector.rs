@@ -0,0 +1,215 @@
+use super::Point;
+
+impl<const N: usize> Point<N> {
+    /// Returns the dot product of the two points, treated as vectors from the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Point;
+    ///
+    /// let a = Point::new([1.0, 2.0, 3.0]);
+    /// let b = Point::new([4.0, -5.0, 6.0]);
+    /// assert_eq!(a.dot(&b), 12.0);
+    /// ```
+    pub fn dot(&self, other: &Point<N>) -> f64 {
+        self.coords
+            .iter()
+            .zip(other.coords.iter())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// Returns the distance of the point from the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Point;
+    ///
+    /// assert_eq!(Point::new([3.0, 4.0]).magnitude(), 5.0);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the point scaled to a magnitude of 1, keeping its direction.
+    ///
+    /// # Panics
+    /// Panics if the point is at the origin, which has no direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Point;
+    ///
+    /// assert_eq!(Point::new([0.0, 5.0]).normalize(), Point::new([0.0, 1.0]));
+    /// ```
+    pub fn normalize(&self) -> Point<N> {
+        let magnitude = self.magnitude();
+        assert!(magnitude != 0.0, "Cannot normalize a zero-magnitude point");
+        *self * (1.0 / magnitude)
+    }
+
+    /// Returns the square of the Euclidean distance between the two points.
+    ///
+    /// This avoids the square root in [`Point::distance`], so it is cheaper when
+    /// distances only need to be compared.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Point;
+    ///
+    /// let a = Point::new([1.0, 1.0]);
+    /// let b = Point::new([4.0, 5.0]);
+    /// assert_eq!(a.distance_squared(&b), 25.0);
+    /// ```
+    pub fn distance_squared(&self, other: &Point<N>) -> f64 {
+        let difference = *other - *self;
+        difference.dot(&difference)
+    }
+
+    /// Returns the Euclidean distance between the two points.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Point;
+    ///
+    /// let a = Point::new([1.0, 1.0]);
+    /// let b = Point::new([4.0, 5.0]);
+    /// assert_eq!(a.distance(&b), 5.0);
+    /// ```
+    pub fn distance(&self, other: &Point<N>) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Linearly interpolates between the two points.
+    ///
+    /// Returns exactly `self` when `t` is `0` and exactly `other` when `t` is `1`.
+    /// Values of `t` outside `[0, 1]` extrapolate along the same line.
+    ///
+    /// # Arguments
+    /// * `other` - The point reached when `t` is `1`
+    /// * `t` - How far to move from `self` towards `other`
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Point;
+    ///
+    /// let a = Point::new([0.0, 2.0]);
+    /// let b = Point::new([4.0, 4.0]);
+    /// assert_eq!(a.lerp(&b, 0.5), Point::new([2.0, 3.0]));
+    /// ```
+    pub fn lerp(&self, other: &Point<N>, t: f64) -> Point<N> {
+        // Weighting both ends, rather than `self + (other - self) * t`, keeps
+        // the endpoints exact
+        Point::new(std::array::from_fn(|i| {
+            self.coords[i] * (1.0 - t) + other.coords[i] * t
+        }))
+    }
+}
+
+impl Point<3> {
+    /// Returns the cross product of the two points, treated as vectors from the origin.
+    ///
+    /// The result is perpendicular to both inputs, following the right-hand rule.
+    ///
+    /// # Examples
+    /// ```
+    /// use mather::Point;
+    ///
+    /// let x = Point::new([1.0, 0.0, 0.0]);
+    /// let y = Point::new([0.0, 1.0, 0.0]);
+    /// assert_eq!(x.cross(&y), Point::new([0.0, 0.0, 1.0]));
+    /// ```
+    pub fn cross(&self, other: &Point<3>) -> Point<3> {
+        let [ax, ay, az] = self.coords;
+        let [bx, by, bz] = other.coords;
+        Point::new([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_magnitude_squared_is_self_dot() {
+        for coords in [[3.0, 4.0, 12.0], [-1.5, 0.25, 2.0], [0.0, 0.0, 0.0]] {
+            let a = Point::new(coords);
+            assert!((a.magnitude().powi(2) - a.dot(&a)).abs() < 1e-12);
+        }
+        assert_eq!(Point::new([3.0, 4.0, 12.0]).magnitude(), 13.0);
+    }
+
+    #[test]
+    fn test_dot_of_perpendicular_points() {
+        let a = Point::new([1.0, 1.0]);
+        let b = Point::new([-2.0, 2.0]);
+
+        assert_eq!(a.dot(&b), 0.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = Point::new([2.0, -2.0, 1.0]).normalize();
+
+        assert!((a.magnitude() - 1.0).abs() < 1e-12);
+        assert_eq!(a, Point::new([2.0 / 3.0, -2.0 / 3.0, 1.0 / 3.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero-magnitude")]
+    fn test_normalize_zero() {
+        Point::new([0.0, 0.0]).normalize();
+    }
+
+    #[test]
+    fn test_cross_of_basis_vectors() {
+        let x = Point::new([1.0, 0.0, 0.0]);
+        let y = Point::new([0.0, 1.0, 0.0]);
+        let z = Point::new([0.0, 0.0, 1.0]);
+
+        assert_eq!(x.cross(&y), z);
+        assert_eq!(y.cross(&z), x);
+        assert_eq!(z.cross(&x), y);
+        assert_eq!(y.cross(&x), -z);
+    }
+
+    #[test]
+    fn test_cross_is_perpendicular() {
+        let a = Point::new([1.0, 2.0, 3.0]);
+        let b = Point::new([-4.0, 0.5, 2.0]);
+        let c = a.cross(&b);
+
+        assert_eq!(c.dot(&a), 0.0);
+        assert_eq!(c.dot(&b), 0.0);
+    }
+
+    #[test]
+    fn test_distance_and_distance_squared_agree() {
+        let a = Point::new([1.0, -2.0, 0.5]);
+        let b = Point::new([-3.0, 4.0, 2.5]);
+
+        assert_eq!(a.distance_squared(&b), 56.0);
+        assert!((a.distance(&b).powi(2) - a.distance_squared(&b)).abs() < 1e-12);
+        assert_eq!(a.distance(&b), b.distance(&a));
+        assert_eq!(a.distance(&a), 0.0);
+    }
+
+    #[test]
+    fn test_lerp_endpoints_are_exact() {
+        let a = Point::new([0.1, 0.7, -3.3]);
+        let b = Point::new([0.2, 1e10, 1e-10]);
+
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+    }
+
+    #[test]
+    fn test_lerp_midpoint_and_extrapolation() {
+        let a = Point::new([1.0, 2.0]);
+        let b = Point::new([3.0, 6.0]);
+
+        assert_eq!(a.lerp(&b, 0.5), Point::new([2.0, 4.0]));
+        assert_eq!(a.lerp(&b, 2.0), Point::new([5.0, 10.0]));
+        assert_eq!(a.lerp(&b, -1.0), Point::new([-1.0, -2.0]));
+    }
+}
@@ -0,0 +1,189 @@
+use std::marker::PhantomData;
+
+use dater::ArcQueue;
+
+use crate::{Worker, WorkerConfig};
+
+/// Marks the half of a [`Dispatcher`] that pushes items.
+#[derive(Clone, Copy, Debug)]
+pub struct SenderHalf;
+
+/// Marks the half of a [`Dispatcher`] that pops items.
+#[derive(Clone, Copy, Debug)]
+pub struct ReceiverHalf;
+
+/// One half of a shared queue of items to be processed by workers.
+///
+/// Dispatchers are created in pairs by `Dispatcher::new`. The sender half can only
+/// push and the receiver half can only pop, so a [`Worker`] given the receiver can't
+/// add items of its own. Both halves can be cloned, and every clone shares the same
+/// underlying queue.
+///
+/// # Type Parameters
+/// * `T` - The type of items in the queue
+/// * `Role` - Either [`SenderHalf`] or [`ReceiverHalf`]
+///
+/// # Examples
+/// ```
+/// use pooler::Dispatcher;
+///
+/// let (sender, receiver) = Dispatcher::new();
+/// sender.push(1);
+/// sender.push(2);
+///
+/// assert_eq!(receiver.pop(), Some(1));
+/// assert_eq!(receiver.pop(), Some(2));
+/// assert_eq!(receiver.pop(), None);
+/// ```
+pub struct Dispatcher<T, Role = ReceiverHalf> {
+    queue: ArcQueue<T>,
+    role: PhantomData<Role>,
+}
+
+impl<T, Role> Clone for Dispatcher<T, Role> {
+    fn clone(&self) -> Self {
+        Dispatcher {
+            queue: self.queue.clone(),
+            role: PhantomData,
+        }
+    }
+}
+
+impl<T> Dispatcher<T, SenderHalf> {
+    /// Creates a new empty queue, split into its sender and receiver halves.
+    ///
+    /// # Returns
+    /// A `(sender, receiver)` pair sharing the same queue
+    pub fn new() -> (Dispatcher<T, SenderHalf>, Dispatcher<T, ReceiverHalf>) {
+        let queue = ArcQueue::new();
+        let sender = Dispatcher {
+            queue: queue.clone(),
+            role: PhantomData,
+        };
+        let receiver = Dispatcher {
+            queue,
+            role: PhantomData,
+        };
+        (sender, receiver)
+    }
+
+    /// Pushes an item onto the back of the queue.
+    ///
+    /// # Arguments
+    /// * `item` - The item to be processed
+    pub fn push(&self, item: T) {
+        self.queue.push(item);
+    }
+}
+
+impl<T> Dispatcher<T, ReceiverHalf> {
+    /// Removes and returns the item at the front of the queue, or `None` if the
+    /// queue is empty.
+    pub fn pop(&self) -> Option<T> {
+        self.queue.pop()
+    }
+}
+
+impl<T, Role> Dispatcher<T, Role> {
+    /// Checks if there are no items waiting in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns the number of items waiting in the queue.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+impl Worker {
+    /// Creates a new worker that processes jobs from the receiver half of a dispatcher.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique identifier for this worker
+    /// * `receiver` - The receiver half of the dispatcher to take jobs from
+    /// * `handler` - The function that processes each job
+    /// * `config` - Polling behaviour of the worker thread
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pooler::{Dispatcher, Worker, WorkerConfig};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let (sender, receiver) = Dispatcher::new();
+    /// let processed = Arc::new(Mutex::new(Vec::new()));
+    /// let sink = processed.clone();
+    /// let worker = Worker::with_dispatcher(0, receiver, move |n: i32| sink.lock().unwrap().push(n), WorkerConfig::default());
+    ///
+    /// sender.push(1);
+    /// worker.shutdown();
+    ///
+    /// assert_eq!(*processed.lock().unwrap(), vec![1]);
+    /// ```
+    pub fn with_dispatcher<T, F>(
+        id: usize,
+        receiver: Dispatcher<T>,
+        handler: F,
+        config: WorkerConfig,
+    ) -> Self
+    where
+        T: Send + 'static,
+        F: Fn(T) + Send + 'static,
+    {
+        Worker::new(id, receiver.queue, handler, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_halves_share_queue() {
+        let (sender, receiver) = Dispatcher::new();
+        let other_sender = sender.clone();
+        let other_receiver = receiver.clone();
+
+        sender.push("a");
+        other_sender.push("b");
+
+        assert_eq!(receiver.len(), 2);
+        assert_eq!(sender.len(), 2);
+        assert_eq!(other_receiver.pop(), Some("a"));
+        assert_eq!(receiver.pop(), Some("b"));
+        assert!(sender.is_empty());
+    }
+
+    #[test]
+    fn test_workers_share_receiver() {
+        let (sender, receiver) = Dispatcher::new();
+        let processed = Arc::new(AtomicUsize::new(0));
+        let workers: Vec<Worker> = (0..3)
+            .map(|id| {
+                let counter = processed.clone();
+                Worker::with_dispatcher(
+                    id,
+                    receiver.clone(),
+                    move |n: usize| {
+                        counter.fetch_add(n, Ordering::SeqCst);
+                    },
+                    WorkerConfig::default(),
+                )
+            })
+            .collect();
+
+        for n in 1..=10 {
+            sender.push(n);
+        }
+        for worker in &workers {
+            worker.shutdown();
+        }
+
+        assert_eq!(processed.load(Ordering::SeqCst), 55);
+        assert!(receiver.is_empty());
+    }
+}
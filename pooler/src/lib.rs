@@ -4,5 +4,17 @@ pub use task::*;
 mod task_queue;
 pub use task_queue::*;
 
+mod result_task_queue;
+pub use result_task_queue::*;
+
 mod signal;
 pub use signal::*;
+
+mod signal_history;
+pub use signal_history::*;
+
+mod worker;
+pub use worker::*;
+
+mod dispatcher;
+pub use dispatcher::*;
@@ -0,0 +1,124 @@
+use crate::TaskQueue;
+use dater::ArcQueue;
+
+/// A [`TaskQueue`] whose handler returns a value for each item.
+///
+/// Results are stored as items finish and can be retrieved with `collect`. Created
+/// with [`TaskQueue::new_with_results`].
+///
+/// # Type Parameters
+/// * `T` - The type of items to be processed
+/// * `R` - The type of result produced for each item
+///
+/// # Examples
+/// ```
+/// use pooler::TaskQueue;
+///
+/// let queue = TaskQueue::new_with_results(2, |num: i32| num * 2);
+/// queue.push(1);
+/// queue.push(2);
+///
+/// let mut results = queue.collect();
+/// results.sort();
+/// assert_eq!(results, vec![2, 4]);
+/// ```
+pub struct ResultTaskQueue<T, R> {
+    queue: TaskQueue<T>,
+    results: ArcQueue<R>,
+}
+
+impl<T> TaskQueue<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Creates a task queue that keeps the value returned by `handler` for each item.
+    ///
+    /// # Arguments
+    /// * `task_count` - The number of worker tasks to create
+    /// * `handler` - The function that processes each item and produces its result
+    ///
+    /// # Returns
+    /// A new `ResultTaskQueue` ready to process items
+    pub fn new_with_results<F, R>(task_count: usize, handler: F) -> ResultTaskQueue<T, R>
+    where
+        F: Fn(T) -> R + Send + Sync + 'static,
+        R: Send + 'static,
+    {
+        let results = ArcQueue::new();
+        let sink = results.clone();
+        let queue = TaskQueue::new(task_count, move |item| sink.push(handler(item)));
+
+        ResultTaskQueue { queue, results }
+    }
+}
+
+impl<T, R> ResultTaskQueue<T, R>
+where
+    T: Send + Sync + 'static,
+    R: Send + 'static,
+{
+    /// Pushes a new item to the task queue for processing.
+    ///
+    /// # Arguments
+    /// * `item` - The item to be processed
+    pub fn push(&self, item: T) {
+        self.queue.push(item);
+    }
+
+    /// Waits for all items to be processed without taking their results.
+    pub fn wait(&self) {
+        self.queue.wait();
+    }
+
+    /// Waits for all pending items and returns their results in completion order.
+    ///
+    /// The returned results are removed from the queue, so each result is returned
+    /// by only one call. Items whose handler panicked produce no result.
+    pub fn collect(&self) -> Vec<R> {
+        self.queue.wait();
+        self.results.drain()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_squares() {
+        let queue = TaskQueue::new_with_results(3, |num: i32| num * num);
+        for num in 1..=6 {
+            queue.push(num);
+        }
+
+        let mut results = queue.collect();
+        results.sort();
+
+        assert_eq!(results, vec![1, 4, 9, 16, 25, 36]);
+    }
+
+    #[test]
+    fn test_collect_takes_results() {
+        let queue = TaskQueue::new_with_results(1, |num: i32| num.to_string());
+        queue.push(1);
+        assert_eq!(queue.collect(), vec!["1".to_string()]);
+
+        assert!(queue.collect().is_empty());
+        queue.push(2);
+        queue.push(3);
+        assert_eq!(queue.collect(), vec!["2".to_string(), "3".to_string()]);
+    }
+
+    #[test]
+    fn test_collect_reports_errors() {
+        let queue = TaskQueue::new_with_results(2, |text: &'static str| text.parse::<i32>());
+        queue.push("7");
+        queue.push("seven");
+
+        let results = queue.collect();
+
+        assert_eq!(results.len(), 2);
+        assert!(results.contains(&Ok(7)));
+        assert_eq!(results.iter().filter(|result| result.is_err()).count(), 1);
+    }
+}
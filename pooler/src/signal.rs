@@ -4,8 +4,29 @@
 //! It allows safe state management across multiple threads with callback support
 //! for reacting to state changes.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
+/// A type alias for listener callbacks that can be safely shared between threads
+type Callback<T> = Box<dyn Fn(&T) + Send>;
+
+/// Identifies a callback registered on a [`Signal`], so it can later be removed.
+///
+/// IDs are unique among all clones of the signal they were issued by.
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Copy, Clone, Debug)]
+pub struct SignalListenerId(usize);
+
+/// A registered callback together with its listener ID
+struct Listener<T> {
+    id: SignalListenerId,
+    callback: Callback<T>,
+    /// Whether the listener is removed after it is first called
+    once: bool,
+}
+
+/// The shared list of registered callbacks
+type Listeners<T> = Arc<Mutex<Vec<Listener<T>>>>;
+
 /// A thread-safe container for reactive state management.
 ///
 /// `Signal<T>` provides a way to manage state that can be safely shared across threads
@@ -27,7 +48,8 @@ use std::sync::{Arc, Mutex};
 /// ```
 pub struct Signal<T> {
     value: Arc<Mutex<T>>,
-    callbacks: Arc<Mutex<Vec<Box<dyn Fn(&T) + Send>>>>,
+    callbacks: Listeners<T>,
+    next_listener_id: Arc<AtomicUsize>,
 }
 
 impl<T> Clone for Signal<T> {
@@ -35,6 +57,7 @@ impl<T> Clone for Signal<T> {
         Signal {
             value: self.value.clone(),
             callbacks: self.callbacks.clone(),
+            next_listener_id: self.next_listener_id.clone(),
         }
     }
 }
@@ -69,6 +92,7 @@ where
         Signal {
             value: Arc::new(Mutex::new(initial_value)),
             callbacks: Arc::new(Mutex::new(Vec::new())),
+            next_listener_id: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -93,10 +117,41 @@ where
     pub fn set(&self, new_value: T) {
         let mut value = self.value.lock().unwrap();
         *value = new_value;
+        self.notify(&value);
+    }
 
-        let callbacks = self.callbacks.lock().unwrap();
-        for callback in callbacks.iter() {
-            callback(&*value);
+    /// Updates the signal's value only if a predicate accepts the change.
+    ///
+    /// The predicate is called with the current value and the proposed new
+    /// value. When it returns `true` the value is replaced and callbacks are
+    /// notified as with [`Signal::set`]; otherwise nothing happens. The check
+    /// and the update happen under the same lock, so no other update can slip
+    /// in between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_value` - The proposed new state value
+    /// * `predicate` - A function taking `(current, new)` that decides whether to update
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pooler::Signal;
+    ///
+    /// let signal = Signal::new(1);
+    /// signal.listen(|value| println!("Changed to: {}", value));
+    /// signal.update_if(1, |old, new| old != new); // No change, no callback
+    /// signal.update_if(2, |old, new| old != new); // Will trigger the callback
+    /// assert_eq!(signal.get(), 2);
+    /// ```
+    pub fn update_if<F>(&self, new_value: T, predicate: F)
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut value = self.value.lock().unwrap();
+        if predicate(&value, &new_value) {
+            *value = new_value;
+            self.notify(&value);
         }
     }
 
@@ -131,7 +186,7 @@ where
     /// # Arguments
     ///
     /// * `callback` - A function that takes an immutable reference to the signal's value.
-    ///               Must implement `Send` to ensure thread safety.
+    ///   Must implement `Send` to ensure thread safety.
     ///
     /// # Examples
     ///
@@ -146,8 +201,136 @@ where
     where
         F: Fn(&T) + Send + 'static,
     {
+        self.listen_with_id(callback);
+    }
+
+    /// Registers a callback and returns an ID that can be used to remove it.
+    ///
+    /// This behaves like [`Signal::listen`], but the returned ID can be passed
+    /// to [`Signal::unlisten`] once the callback is no longer needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - A function that takes an immutable reference to the signal's value.
+    ///   Must implement `Send` to ensure thread safety.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the registered callback.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pooler::Signal;
+    ///
+    /// let signal = Signal::new(0);
+    /// let id = signal.listen_with_id(|value| println!("Value changed to: {}", value));
+    /// signal.set(1); // Will trigger the callback
+    /// signal.unlisten(id);
+    /// signal.set(2); // Will not trigger the callback
+    /// ```
+    pub fn listen_with_id<F>(&self, callback: F) -> SignalListenerId
+    where
+        F: Fn(&T) + Send + 'static,
+    {
+        self.register(Box::new(callback), false)
+    }
+
+    /// Registers a callback that is called on the next change only.
+    ///
+    /// The callback fires exactly once, on the first `set` after it is
+    /// registered, and is then removed automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - A function that takes an immutable reference to the signal's value.
+    ///   Must implement `Send` to ensure thread safety.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pooler::Signal;
+    ///
+    /// let signal = Signal::new(0);
+    /// signal.listen_once(|value| println!("First change: {}", value));
+    /// signal.set(1); // Will trigger the callback
+    /// signal.set(2); // Will not trigger the callback
+    /// ```
+    pub fn listen_once<F>(&self, callback: F)
+    where
+        F: Fn(&T) + Send + 'static,
+    {
+        self.register(Box::new(callback), true);
+    }
+
+    /// Removes a callback previously registered with [`Signal::listen_with_id`].
+    ///
+    /// Removing an ID that was already removed has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID returned when the callback was registered
+    pub fn unlisten(&self, id: SignalListenerId) {
         let mut callbacks = self.callbacks.lock().unwrap();
-        callbacks.push(Box::new(callback));
+        callbacks.retain(|listener| listener.id != id);
+    }
+
+    /// Creates a derived signal whose value is computed from this one.
+    ///
+    /// The derived signal starts with `f` applied to the current value, and is
+    /// updated with a freshly computed value every time this signal is set.
+    /// Listeners on the derived signal are notified as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function computing the derived value from this signal's value
+    ///
+    /// # Returns
+    ///
+    /// A new `Signal<U>` that follows this signal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pooler::Signal;
+    ///
+    /// let items = Signal::new(vec![1, 2, 3]);
+    /// let count = items.map(|items| items.len());
+    /// assert_eq!(count.get(), 3);
+    ///
+    /// items.set(vec![1]);
+    /// assert_eq!(count.get(), 1);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Signal<U>
+    where
+        F: Fn(&T) -> U + Send + 'static,
+        U: Send + Sync + 'static,
+    {
+        let derived = {
+            let value = self.value.lock().unwrap();
+            Signal::new(f(&*value))
+        };
+
+        let target = derived.clone();
+        self.listen(move |value| target.set(f(value)));
+        derived
+    }
+
+    /// Calls every registered callback with the value, then drops one-shot listeners.
+    fn notify(&self, value: &T) {
+        let mut callbacks = self.callbacks.lock().unwrap();
+        for listener in callbacks.iter() {
+            (listener.callback)(value);
+        }
+        callbacks.retain(|listener| !listener.once);
+    }
+
+    /// Stores a callback under a new listener ID.
+    fn register(&self, callback: Callback<T>, once: bool) -> SignalListenerId {
+        let id = SignalListenerId(self.next_listener_id.fetch_add(1, Ordering::Relaxed));
+        let mut callbacks = self.callbacks.lock().unwrap();
+        callbacks.push(Listener { id, callback, once });
+        id
     }
 }
 
@@ -255,4 +438,152 @@ mod tests {
         let signal: Signal<i32> = Signal::default();
         assert_eq!(signal.get(), 0);
     }
+
+    #[test]
+    fn test_unlisten_stops_callback() {
+        let signal = Signal::new(0);
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+
+        let id = signal.listen_with_id(move |_| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        signal.set(1);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+        signal.unlisten(id);
+        signal.set(2);
+        assert_eq!(
+            counter.load(Ordering::SeqCst),
+            1,
+            "Removed callback should not be called"
+        );
+    }
+
+    #[test]
+    fn test_unlisten_keeps_other_callbacks() {
+        let signal = Signal::new(0);
+        let first = Arc::new(AtomicU32::new(0));
+        let second = Arc::new(AtomicU32::new(0));
+        let first_clone = first.clone();
+        let second_clone = second.clone();
+
+        let first_id = signal.listen_with_id(move |_| {
+            first_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let second_id = signal.clone().listen_with_id(move |_| {
+            second_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_ne!(first_id, second_id, "Clones should share the ID sequence");
+
+        signal.unlisten(first_id);
+        signal.unlisten(first_id);
+        signal.set(1);
+
+        assert_eq!(first.load(Ordering::SeqCst), 0);
+        assert_eq!(second.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_listen_once_fires_once() {
+        let signal = Signal::new(0);
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+
+        signal.listen_once(move |value| {
+            assert_eq!(*value, 1);
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        signal.set(1);
+        signal.set(2);
+        signal.set(3);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_listen_once_alongside_listen() {
+        let signal = Signal::new(0);
+        let once = Arc::new(AtomicU32::new(0));
+        let always = Arc::new(AtomicU32::new(0));
+        let once_clone = once.clone();
+        let always_clone = always.clone();
+
+        signal.listen_once(move |_| {
+            once_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        signal.listen(move |_| {
+            always_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        signal.set(1);
+        signal.set(2);
+        assert_eq!(once.load(Ordering::SeqCst), 1);
+        assert_eq!(always.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_map_follows_source() {
+        let source = Signal::new(vec![1, 2, 3]);
+        let length = source.map(|items| format!("{} items", items.len()));
+        assert_eq!(length.get(), "3 items");
+
+        source.set(vec![]);
+        assert_eq!(length.get(), "0 items");
+
+        source.set(vec![4, 5]);
+        assert_eq!(length.get(), "2 items");
+    }
+
+    #[test]
+    fn test_map_notifies_derived_listeners() {
+        let source = Signal::new(1);
+        let doubled = source.map(|value| value * 2);
+        let squared = doubled.map(|value| value * value);
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+
+        doubled.listen(move |value| {
+            assert_eq!(*value, 10);
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        source.set(5);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(squared.get(), 100, "Derived signals should chain");
+    }
+
+    #[test]
+    fn test_update_if_accepted() {
+        let signal = Signal::new(1);
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        signal.listen(move |_| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        signal.update_if(2, |old, new| old != new);
+        assert_eq!(signal.get(), 2);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_update_if_rejected() {
+        let signal = Signal::new(5);
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        signal.listen(move |_| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        signal.update_if(5, |old, new| old != new);
+        signal.update_if(3, |old, new| new > old);
+        assert_eq!(
+            signal.get(),
+            5,
+            "Rejected updates should not change the value"
+        );
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+    }
 }
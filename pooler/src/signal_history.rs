@@ -0,0 +1,226 @@
+//! A reactive state container that remembers its previous values.
+//!
+//! This module provides `SignalWithHistory<T>`, which wraps a [`Signal`] and keeps
+//! a bounded history of earlier values so that changes can be undone.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use crate::Signal;
+
+/// A [`Signal`] that records its previous values in a fixed-capacity ring buffer.
+///
+/// Every `set` pushes the value being replaced into the history. When the history
+/// is full the oldest entry is discarded. `undo` restores the most recent entry.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of value to be stored. Must implement `Clone + Send + Sync + 'static`
+///
+/// # Examples
+///
+/// ```
+/// use pooler::Signal;
+///
+/// let text = Signal::with_history(String::from("a"), 10);
+/// text.set(String::from("ab"));
+/// text.set(String::from("abc"));
+///
+/// text.undo();
+/// assert_eq!(text.get(), "ab");
+/// ```
+pub struct SignalWithHistory<T> {
+    signal: Signal<T>,
+    history: Arc<Mutex<VecDeque<T>>>,
+    capacity: usize,
+}
+
+impl<T> Clone for SignalWithHistory<T> {
+    fn clone(&self) -> Self {
+        SignalWithHistory {
+            signal: self.signal.clone(),
+            history: self.history.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl<T> Signal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Creates a new signal that keeps up to `capacity` previous values.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial` - The initial state value
+    /// * `capacity` - The maximum number of previous values to remember
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pooler::Signal;
+    ///
+    /// let signal = Signal::with_history(0, 3);
+    /// assert!(signal.history().is_empty());
+    /// ```
+    pub fn with_history(initial: T, capacity: usize) -> SignalWithHistory<T> {
+        SignalWithHistory {
+            signal: Signal::new(initial),
+            history: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+}
+
+impl<T> SignalWithHistory<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Updates the value, recording the previous one in the history.
+    ///
+    /// Callbacks are notified as with [`Signal::set`].
+    ///
+    /// # Arguments
+    ///
+    /// * `new_value` - The new state value
+    pub fn set(&self, new_value: T) {
+        if self.capacity > 0 {
+            let mut history = self.history.lock().unwrap();
+            if history.len() == self.capacity {
+                history.pop_front();
+            }
+            history.push_back(self.signal.get());
+        }
+        // The history lock is released first, so callbacks can use this signal
+        self.signal.set(new_value);
+    }
+
+    /// Retrieves a clone of the current value.
+    pub fn get(&self) -> T {
+        self.signal.get()
+    }
+
+    /// Registers a callback to be called when the value changes, including on `undo`.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - A function that takes an immutable reference to the signal's value
+    pub fn listen<F>(&self, callback: F)
+    where
+        F: Fn(&T) + Send + 'static,
+    {
+        self.signal.listen(callback);
+    }
+
+    /// Returns the remembered previous values, oldest first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pooler::Signal;
+    ///
+    /// let signal = Signal::with_history(1, 2);
+    /// signal.set(2);
+    /// signal.set(3);
+    /// signal.set(4);
+    /// assert_eq!(signal.history(), vec![2, 3]);
+    /// ```
+    pub fn history(&self) -> Vec<T> {
+        let history = self.history.lock().unwrap();
+        history.iter().cloned().collect()
+    }
+
+    /// Restores the most recent previous value and notifies callbacks.
+    ///
+    /// The restored value is removed from the history, so repeated calls step
+    /// further back. Does nothing if the history is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a value was restored, `false` if the history was empty.
+    pub fn undo(&self) -> bool {
+        let previous = self.history.lock().unwrap().pop_back();
+        match previous {
+            Some(previous) => {
+                self.signal.set(previous);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_history_empty_before_set() {
+        let signal = Signal::with_history(0, 5);
+        assert!(signal.history().is_empty());
+        assert!(!signal.undo(), "Nothing to undo yet");
+        assert_eq!(signal.get(), 0);
+    }
+
+    #[test]
+    fn test_history_capped_at_capacity() {
+        let signal = Signal::with_history(0, 3);
+        for i in 1..=10 {
+            signal.set(i);
+        }
+
+        assert_eq!(signal.history(), vec![7, 8, 9]);
+        assert_eq!(signal.get(), 10);
+    }
+
+    #[test]
+    fn test_undo_restores_and_notifies() {
+        let signal = Signal::with_history(String::from("first"), 4);
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        signal.listen(move |_| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        signal.set(String::from("second"));
+        signal.set(String::from("third"));
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+        assert!(signal.undo());
+        assert_eq!(signal.get(), "second");
+        assert_eq!(counter.load(Ordering::SeqCst), 3, "Undo should notify");
+
+        assert!(signal.undo());
+        assert_eq!(signal.get(), "first");
+        assert!(!signal.undo());
+        assert!(signal.history().is_empty());
+    }
+
+    #[test]
+    fn test_zero_capacity_keeps_no_history() {
+        let signal = Signal::with_history(1, 0);
+        signal.set(2);
+
+        assert!(signal.history().is_empty());
+        assert!(!signal.undo());
+        assert_eq!(signal.get(), 2);
+    }
+
+    #[test]
+    fn test_listener_can_read_history() {
+        let signal = Signal::with_history(0, 5);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let observed = signal.clone();
+        let sink = seen.clone();
+        signal.listen(move |_| sink.lock().unwrap().push(observed.history()));
+
+        signal.set(1);
+        signal.set(2);
+        signal.undo();
+
+        assert_eq!(*seen.lock().unwrap(), vec![vec![0], vec![0, 1], vec![0]]);
+    }
+}
@@ -1,9 +1,16 @@
+// The deprecated type still needs its own implementation to compile cleanly
+#![allow(deprecated)]
+
 use std::{
     panic::AssertUnwindSafe,
     sync::{Arc, Mutex},
     thread,
 };
 
+/// A thread that repeatedly runs a handler until it is dropped.
+///
+/// `TaskQueue` no longer uses this type; run jobs on a [`crate::Worker`] instead.
+#[deprecated(note = "use `Worker` instead")]
 pub struct Task {
     thread: Option<thread::JoinHandle<()>>,
 
@@ -59,9 +66,6 @@ impl Task {
                     // Signal that the thread is no longer processing
                     let mut processing = thread_is_processing.lock().unwrap();
                     *processing = false;
-
-                    // If the handler returns true, we can break the loop
-                    return;
                 }
             }));
 
@@ -1,6 +1,50 @@
-use crate::Task;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{Worker, WorkerConfig};
 use dater::ArcQueue;
 
+/// Configuration for a [`TaskQueue`].
+///
+/// The `worker` settings are passed to every worker in the queue. See
+/// [`WorkerConfig`] for the latency and CPU tradeoffs of the polling intervals.
+///
+/// # Examples
+/// ```
+/// use pooler::{TaskQueue, TaskQueueConfig, WorkerConfig};
+/// use std::time::Duration;
+///
+/// let config = TaskQueueConfig {
+///     worker: WorkerConfig {
+///         idle_sleep: Duration::from_millis(1),
+///         busy_check_interval: Duration::from_millis(1),
+///     },
+///     ..TaskQueueConfig::default()
+/// };
+/// let queue = TaskQueue::with_config(2, config, |num: i32| {
+///     println!("Processing {}", num);
+/// });
+/// queue.push(1);
+/// queue.wait();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TaskQueueConfig {
+    /// Polling behaviour of each worker
+    pub worker: WorkerConfig,
+    /// How often `wait` checks whether processing has finished
+    pub wait_interval: Duration,
+}
+
+impl Default for TaskQueueConfig {
+    fn default() -> Self {
+        TaskQueueConfig {
+            worker: WorkerConfig::default(),
+            wait_interval: Duration::from_millis(100),
+        }
+    }
+}
+
 /// A concurrent task queue that processes items of type `T` using a fixed pool of worker tasks.
 ///
 /// # Purpose
@@ -36,8 +80,12 @@ use dater::ArcQueue;
 /// assert_eq!(*counter.lock().unwrap(), 3);
 /// ```
 pub struct TaskQueue<T> {
-    tasks: Vec<Task>,
+    workers: Vec<Worker>,
     backlog: ArcQueue<T>,
+    config: TaskQueueConfig,
+    handler: Arc<dyn Fn(T) + Send + Sync>,
+    next_worker_id: usize,
+    paused: Arc<AtomicBool>,
 }
 
 impl<T> TaskQueue<T>
@@ -60,23 +108,66 @@ where
     where
         F: Fn(T) + Send + Sync + 'static,
     {
-        let backlog = ArcQueue::new();
-        let handler = std::sync::Arc::new(handler);
+        Self::with_config(task_count, TaskQueueConfig::default(), handler)
+    }
 
-        let mut tasks = Vec::with_capacity(task_count);
+    /// Creates a new `TaskQueue` with the specified number of worker tasks and configuration.
+    ///
+    /// # Arguments
+    /// * `task_count` - The number of worker tasks to create
+    /// * `config` - The polling behaviour of the workers and of `wait()`
+    /// * `handler` - The function that processes each item. Must be `Send + Sync + 'static`
+    ///
+    /// # Returns
+    /// A new `TaskQueue` instance ready to process items
+    pub fn with_config<F>(task_count: usize, config: TaskQueueConfig, handler: F) -> Self
+    where
+        F: Fn(T) + Send + Sync + 'static,
+    {
+        let mut queue = Self {
+            workers: Vec::with_capacity(task_count),
+            backlog: ArcQueue::new(),
+            config,
+            handler: Arc::new(handler),
+            next_worker_id: 0,
+            paused: Arc::new(AtomicBool::new(false)),
+        };
         for _ in 0..task_count {
-            let thread_backlog = backlog.clone();
-            let handler = handler.clone();
+            queue.add_worker();
+        }
+        queue
+    }
 
-            tasks.push(Task::new(move || {
-                if let Some(item) = thread_backlog.pop() {
-                    handler(item);
-                }
+    /// Spawns an additional worker that shares the backlog and handler.
+    ///
+    /// The new worker starts taking items from the backlog immediately.
+    pub fn add_worker(&mut self) {
+        let handler = self.handler.clone();
+        self.workers.push(Worker::with_pause_flag(
+            self.next_worker_id,
+            self.backlog.clone(),
+            move |item| handler(item),
+            self.config.worker,
+            self.paused.clone(),
+        ));
+        self.next_worker_id += 1;
+    }
 
-                true
-            }));
+    /// Cancels and removes the most recently added worker.
+    ///
+    /// The worker finishes the item it is currently processing before this returns.
+    /// Items still in the backlog are left for the remaining workers. Does nothing
+    /// if there are no workers.
+    pub fn remove_worker(&mut self) {
+        if let Some(worker) = self.workers.pop() {
+            worker.cancel();
+            // Dropping the worker waits for its thread to exit
         }
-        Self { tasks, backlog }
+    }
+
+    /// Returns the number of workers currently in the queue.
+    pub fn worker_count(&self) -> usize {
+        self.workers.len()
     }
 
     /// Pushes a new item to the task queue for processing.
@@ -106,7 +197,26 @@ where
     /// # Returns
     /// `true` if any worker tasks are busy processing items, `false` otherwise
     pub fn is_busy(&self) -> bool {
-        self.tasks.iter().any(|task| task.is_busy())
+        self.workers.iter().any(|worker| worker.is_busy())
+    }
+
+    /// Stops the workers from taking new items from the backlog.
+    ///
+    /// Items already being processed are finished. Items pushed while paused stay in
+    /// the backlog until `resume` is called. Note that `wait` does not return while
+    /// the queue is paused with items in the backlog.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Lets the workers take items from the backlog again after a `pause`.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    /// Checks if the task queue is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
     }
 
     /// Waits for all items to be processed.
@@ -118,7 +228,126 @@ where
     /// This ensures that all pushed items have been fully processed before returning.
     pub fn wait(&self) {
         while !self.is_empty() || self.is_busy() {
-            std::thread::sleep(std::time::Duration::from_millis(100));
+            std::thread::sleep(self.config.wait_interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorkerConfig;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread::sleep;
+
+    fn fast_config() -> TaskQueueConfig {
+        TaskQueueConfig {
+            worker: WorkerConfig {
+                idle_sleep: Duration::from_millis(1),
+                busy_check_interval: Duration::from_millis(1),
+            },
+            wait_interval: Duration::from_millis(1),
+        }
+    }
+
+    /// Counts processed items and the largest number of items processed at once.
+    #[derive(Clone, Default)]
+    struct Tracker {
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+        processed: Arc<AtomicUsize>,
+    }
+
+    impl Tracker {
+        fn handle(&self) {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            sleep(Duration::from_millis(10));
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            self.processed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_workers() {
+        let tracker = Tracker::default();
+        let handler_tracker = tracker.clone();
+        let mut queue =
+            TaskQueue::with_config(1, fast_config(), move |_: i32| handler_tracker.handle());
+
+        for n in 0..5 {
+            queue.push(n);
         }
+        queue.wait();
+        assert_eq!(tracker.max_running.load(Ordering::SeqCst), 1);
+
+        for n in 0..20 {
+            queue.push(n);
+        }
+        queue.add_worker();
+        queue.add_worker();
+        queue.add_worker();
+        assert_eq!(queue.worker_count(), 4);
+        queue.wait();
+
+        assert_eq!(tracker.processed.load(Ordering::SeqCst), 25);
+        assert!(
+            tracker.max_running.load(Ordering::SeqCst) > 1,
+            "Added workers should process items in parallel"
+        );
+
+        queue.remove_worker();
+        queue.remove_worker();
+        queue.remove_worker();
+        assert_eq!(queue.worker_count(), 1);
+
+        for n in 0..5 {
+            queue.push(n);
+        }
+        queue.wait();
+        assert_eq!(tracker.processed.load(Ordering::SeqCst), 30);
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let processed = Arc::new(AtomicUsize::new(0));
+        let counter = processed.clone();
+        let queue = TaskQueue::with_config(2, fast_config(), move |_: i32| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        for n in 0..10 {
+            queue.push(n);
+        }
+        queue.wait();
+        queue.pause();
+        assert!(queue.is_paused());
+        for n in 10..20 {
+            queue.push(n);
+        }
+
+        while queue.is_busy() {
+            sleep(Duration::from_millis(1));
+        }
+        sleep(Duration::from_millis(20));
+        assert!(!queue.is_busy());
+        assert_eq!(processed.load(Ordering::SeqCst), 10);
+        assert!(!queue.is_empty());
+
+        queue.resume();
+        queue.wait();
+        assert_eq!(processed.load(Ordering::SeqCst), 20);
+    }
+
+    #[test]
+    fn test_remove_worker_when_empty() {
+        let mut queue = TaskQueue::with_config(1, fast_config(), |_: i32| {});
+
+        queue.remove_worker();
+        queue.remove_worker();
+
+        assert_eq!(queue.worker_count(), 0);
+        queue.push(1);
+        assert!(!queue.is_empty(), "No worker is left to take the item");
     }
 }
@@ -1,37 +1,89 @@
 use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, sleep};
 use std::time::Duration;
 
-use crate::{structures::LinearData, Dispatcher};
+use dater::ArcQueue;
+
+/// Controls how often a [`Worker`] polls its queue for new jobs.
+///
+/// Workers poll the shared queue rather than blocking on it, so these durations
+/// trade latency against CPU usage. Shorter sleeps pick up new jobs sooner but
+/// wake the thread more often, which costs CPU time even when the queue is idle.
+/// Longer sleeps are cheaper but add up to one full sleep of latency per job.
+///
+/// # Examples
+///
+/// ```
+/// use pooler::WorkerConfig;
+/// use std::time::Duration;
+///
+/// let config = WorkerConfig {
+///     idle_sleep: Duration::from_millis(1),
+///     ..WorkerConfig::default()
+/// };
+/// assert_eq!(config.busy_check_interval, Duration::from_millis(10));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkerConfig {
+    /// How long the worker sleeps after finding the queue empty.
+    pub idle_sleep: Duration,
+    /// How long the worker sleeps after finishing a job before polling again.
+    pub busy_check_interval: Duration,
+}
+
+impl Default for WorkerConfig {
+    /// Polls every 10ms, both when idle and between jobs.
+    fn default() -> Self {
+        WorkerConfig {
+            idle_sleep: Duration::from_millis(10),
+            busy_check_interval: Duration::from_millis(10),
+        }
+    }
+}
+
+/// A callback invoked with the running panic count each time a job panics.
+type PanicCallback = Arc<dyn Fn(usize) + Send + Sync>;
+
+/// Returns the number of jobs waiting in a worker's queue.
+type BacklogLen = Box<dyn Fn() -> usize + Send + Sync>;
 
 /// A worker thread that executes jobs from a shared job queue.
 ///
-/// Workers run in their own thread and continuously process jobs until dropped.
-/// Each worker has a unique ID and maintains its own thread handle.
+/// Workers run in their own thread and continuously process jobs until dropped,
+/// canceled or shut down. Each worker has a unique ID and maintains its own thread
+/// handle.
 ///
 /// # Panic Safety
 ///
 /// Workers catch panics from job execution to prevent them from crashing the thread.
 /// This allows the worker to continue processing other jobs even if one job panics.
+/// Recovered panics are counted by `panic_count`, and can be observed with `on_panic`.
 pub struct Worker {
     id: usize,
-    thread: Option<thread::JoinHandle<()>>,
+    thread: Mutex<Option<thread::JoinHandle<()>>>,
 
     is_canceled: Arc<Mutex<bool>>,
+    /// How many more jobs the worker takes before a requested shutdown completes,
+    /// or `None` while no shutdown has been requested.
+    shutdown_budget: Arc<Mutex<Option<usize>>>,
     is_processing: Arc<Mutex<bool>>,
+    backlog_len: BacklogLen,
+
+    panic_count: Arc<AtomicUsize>,
+    on_panic: Arc<Mutex<Option<PanicCallback>>>,
 }
 
 impl Drop for Worker {
     fn drop(&mut self) {
-        println!("[Worker {}] Dropped, cancelling thread.", self.id);
         {
             // Signal the worker to stop running
             let mut is_canceled = self.is_canceled.lock().unwrap();
             *is_canceled = true;
         }
 
-        if let Some(thread) = self.thread.take() {
+        if let Some(thread) = self.thread.get_mut().unwrap().take() {
             thread.join().unwrap();
         }
     }
@@ -44,21 +96,68 @@ impl Worker {
     ///
     /// * `id` - Unique identifier for this worker
     /// * `receiver` - Shared receiver for jobs
+    /// * `handler` - The function that processes each job
+    /// * `config` - Polling behaviour of the worker thread
     ///
     /// # Returns
     ///
     /// A new `Worker` instance
-    pub fn new<T, F>(id: usize, mut receiver: Dispatcher<T>, handler: F) -> Self
+    pub fn new<T, F>(id: usize, receiver: ArcQueue<T>, handler: F, config: WorkerConfig) -> Self
+    where
+        T: Send + 'static,
+        F: Fn(T) + Send + 'static,
+    {
+        Self::with_pause_flag(
+            id,
+            receiver,
+            handler,
+            config,
+            Arc::new(AtomicBool::new(false)),
+        )
+    }
+
+    /// Creates a new worker that stops taking jobs while `paused` is set.
+    ///
+    /// The flag can be shared between several workers to pause them together. A
+    /// paused worker finishes its current job, then leaves the queue untouched
+    /// until the flag is cleared. A requested `shutdown` overrides the pause so
+    /// that the queue can still be drained.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique identifier for this worker
+    /// * `receiver` - Shared receiver for jobs
+    /// * `handler` - The function that processes each job
+    /// * `config` - Polling behaviour of the worker thread
+    /// * `paused` - Shared flag that pauses the worker while `true`
+    ///
+    /// # Returns
+    ///
+    /// A new `Worker` instance
+    pub fn with_pause_flag<T, F>(
+        id: usize,
+        receiver: ArcQueue<T>,
+        handler: F,
+        config: WorkerConfig,
+        paused: Arc<AtomicBool>,
+    ) -> Self
     where
         T: Send + 'static,
         F: Fn(T) + Send + 'static,
     {
         let is_canceled = Arc::new(Mutex::new(false));
+        let shutdown_budget: Arc<Mutex<Option<usize>>> = Arc::new(Mutex::new(None));
         let is_processing = Arc::new(Mutex::new(false));
+        let panic_count = Arc::new(AtomicUsize::new(0));
+        let on_panic: Arc<Mutex<Option<PanicCallback>>> = Arc::new(Mutex::new(None));
 
         let thread_is_canceled = is_canceled.clone();
+        let thread_shutdown_budget = shutdown_budget.clone();
         let thread_is_processing = is_processing.clone();
+        let thread_panic_count = panic_count.clone();
+        let thread_on_panic = on_panic.clone();
 
+        let backlog = receiver.clone();
         let thread = thread::spawn(move || loop {
             // Check if the worker has been signaled to stop
             let is_cancelled = {
@@ -66,36 +165,76 @@ impl Worker {
                 *guard
             };
             if is_cancelled {
-                println!("[Worker {}] Is cancelled.", id);
                 break;
             }
 
-            if let Some(payload) = receiver.pop() {
-                // Set the processing flag to true
-                {
-                    let mut processing = thread_is_processing.lock().unwrap();
-                    *processing = true;
-                }
+            if paused.load(Ordering::SeqCst) && thread_shutdown_budget.lock().unwrap().is_none() {
+                sleep(config.idle_sleep);
+                continue;
+            }
 
-                _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            // The busy flag stays locked while popping, so an item is never observed
+            // as neither queued nor in progress. The budget stays locked too, so
+            // `shutdown` can't take its snapshot of the backlog between the check
+            // and the pop.
+            let (payload, is_drained) = {
+                let mut processing = thread_is_processing.lock().unwrap();
+                let mut budget = thread_shutdown_budget.lock().unwrap();
+                let (payload, is_drained) = match *budget {
+                    Some(0) => (None, true),
+                    Some(remaining) => {
+                        let payload = receiver.pop();
+                        if payload.is_some() {
+                            *budget = Some(remaining - 1);
+                        }
+                        let is_drained = payload.is_none();
+                        (payload, is_drained)
+                    }
+                    None => (receiver.pop(), false),
+                };
+                *processing = payload.is_some();
+                (payload, is_drained)
+            };
+            let has_payload = payload.is_some();
+            if is_drained {
+                // Every job queued before the shutdown has been taken
+                break;
+            }
+            if let Some(payload) = payload {
+                let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     handler(payload);
                 }));
-
-                {
-                    let mut processing = thread_is_processing.lock().unwrap();
-                    *processing = false;
+                if result.is_err() {
+                    let count = thread_panic_count.fetch_add(1, Ordering::SeqCst) + 1;
+                    // Clone the callback out so it isn't called with the lock held
+                    let callback = thread_on_panic.lock().unwrap().clone();
+                    if let Some(callback) = callback {
+                        _ = std::panic::catch_unwind(AssertUnwindSafe(|| callback(count)));
+                    }
                 }
             }
 
-            // Sleep for a short duration to prevent busy waiting
-            sleep(Duration::from_millis(10));
+            {
+                let mut processing = thread_is_processing.lock().unwrap();
+                *processing = false;
+            }
+
+            if has_payload {
+                sleep(config.busy_check_interval);
+            } else {
+                sleep(config.idle_sleep);
+            }
         });
 
         Self {
             id,
-            thread: Some(thread),
+            thread: Mutex::new(Some(thread)),
             is_canceled,
+            shutdown_budget,
             is_processing,
+            backlog_len: Box::new(move || backlog.len()),
+            panic_count,
+            on_panic,
         }
     }
 
@@ -109,7 +248,7 @@ impl Worker {
     ///
     /// `true` if the worker thread has finished, `false` otherwise.
     pub fn is_finished(&self) -> bool {
-        if let Some(t) = &self.thread {
+        if let Some(t) = &*self.thread.lock().unwrap() {
             return t.is_finished();
         }
         true
@@ -125,9 +264,250 @@ impl Worker {
         *is_canceled
     }
 
+    /// Returns the number of jobs that have panicked on this worker.
+    pub fn panic_count(&self) -> usize {
+        self.panic_count.load(Ordering::SeqCst)
+    }
+
+    /// Registers a callback that runs on the worker thread each time a job panics.
+    ///
+    /// The callback receives the panic count including the panic that triggered it.
+    /// Registering a new callback replaces the previous one. A panic inside the
+    /// callback itself is caught and ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to call after a recovered panic
+    pub fn on_panic<F>(&mut self, f: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        let mut on_panic = self.on_panic.lock().unwrap();
+        *on_panic = Some(Arc::new(f));
+    }
+
+    /// Checks if `shutdown` has been called on this worker.
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.shutdown_budget.lock().unwrap().is_some()
+    }
+
+    /// Stops the worker as soon as it finishes its current job.
+    ///
+    /// Any jobs still waiting in the queue are left unprocessed. This does not wait
+    /// for the thread to exit; use `shutdown` to drain the queue and wait.
     pub fn cancel(&self) {
-        println!("[Worker {}] Got request to cancel.", self.id);
         let mut is_canceled = self.is_canceled.lock().unwrap();
         *is_canceled = true;
     }
+
+    /// Stops the worker once the current backlog is drained and waits for its thread to exit.
+    ///
+    /// The length of the queue is recorded when this is called, and the worker stops
+    /// after taking that many more jobs or finding the queue empty. Every job pushed
+    /// before this call is processed before it returns, unless another worker sharing
+    /// the queue takes it first. Jobs pushed afterwards, including those pushed by the
+    /// handler itself, are left in the queue. Calling this again, or after the thread
+    /// has already exited, returns immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dater::ArcQueue;
+    /// use pooler::{Worker, WorkerConfig};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let queue = ArcQueue::new();
+    /// let processed = Arc::new(Mutex::new(Vec::new()));
+    /// let sink = processed.clone();
+    /// let worker = Worker::new(0, queue.clone(), move |n: i32| sink.lock().unwrap().push(n), WorkerConfig::default());
+    ///
+    /// queue.push(1);
+    /// queue.push(2);
+    /// worker.shutdown();
+    ///
+    /// assert_eq!(*processed.lock().unwrap(), vec![1, 2]);
+    /// assert!(worker.is_finished());
+    /// ```
+    pub fn shutdown(&self) {
+        {
+            let mut shutdown_budget = self.shutdown_budget.lock().unwrap();
+            if shutdown_budget.is_none() {
+                *shutdown_budget = Some((self.backlog_len)());
+            }
+        }
+
+        let thread = self.thread.lock().unwrap().take();
+        if let Some(thread) = thread {
+            thread.join().unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fast_config() -> WorkerConfig {
+        WorkerConfig {
+            idle_sleep: Duration::from_millis(1),
+            busy_check_interval: Duration::from_millis(1),
+        }
+    }
+
+    #[test]
+    fn test_shutdown_drains_backlog() {
+        let queue = ArcQueue::new();
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let sink = processed.clone();
+        let worker = Worker::new(
+            0,
+            queue.clone(),
+            move |n: i32| {
+                sleep(Duration::from_millis(2));
+                sink.lock().unwrap().push(n);
+            },
+            fast_config(),
+        );
+
+        for n in 0..10 {
+            queue.push(n);
+        }
+        worker.shutdown();
+
+        assert_eq!(*processed.lock().unwrap(), (0..10).collect::<Vec<_>>());
+        assert!(queue.is_empty());
+        assert!(worker.is_finished());
+        assert!(worker.is_shutdown_requested());
+        assert!(!worker.is_canceled());
+    }
+
+    #[test]
+    fn test_shutdown_twice_returns() {
+        let queue: ArcQueue<i32> = ArcQueue::new();
+        let worker = Worker::new(0, queue, |_| {}, fast_config());
+
+        worker.shutdown();
+        worker.shutdown();
+
+        assert!(worker.is_finished());
+    }
+
+    #[test]
+    fn test_panics_are_counted() {
+        let queue = ArcQueue::new();
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let sink = processed.clone();
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let reported_sink = reported.clone();
+        let mut worker = Worker::new(
+            0,
+            queue.clone(),
+            move |n: i32| {
+                if n % 3 == 0 {
+                    panic!("Multiple of three: {}", n);
+                }
+                sink.lock().unwrap().push(n);
+            },
+            fast_config(),
+        );
+        worker.on_panic(move |count| reported_sink.lock().unwrap().push(count));
+
+        for n in 1..=7 {
+            queue.push(n);
+        }
+        worker.shutdown();
+
+        assert_eq!(worker.panic_count(), 2);
+        assert_eq!(*reported.lock().unwrap(), vec![1, 2]);
+        assert_eq!(*processed.lock().unwrap(), vec![1, 2, 4, 5, 7]);
+    }
+
+    #[test]
+    fn test_pause_flag() {
+        let queue = ArcQueue::new();
+        let paused = Arc::new(AtomicBool::new(true));
+        let processed = Arc::new(AtomicUsize::new(0));
+        let counter = processed.clone();
+        let worker = Worker::with_pause_flag(
+            0,
+            queue.clone(),
+            move |_: i32| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            },
+            fast_config(),
+            paused.clone(),
+        );
+
+        queue.push(1);
+        queue.push(2);
+        sleep(Duration::from_millis(20));
+        assert_eq!(processed.load(Ordering::SeqCst), 0);
+        assert!(!worker.is_busy());
+
+        paused.store(false, Ordering::SeqCst);
+        worker.shutdown();
+        assert_eq!(processed.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_cancel_leaves_backlog() {
+        let queue = ArcQueue::new();
+        let worker = Worker::new(
+            0,
+            queue.clone(),
+            |_: i32| sleep(Duration::from_millis(20)),
+            fast_config(),
+        );
+
+        for n in 0..10 {
+            queue.push(n);
+        }
+        worker.cancel();
+        while !worker.is_finished() {
+            sleep(Duration::from_millis(1));
+        }
+
+        assert!(worker.is_canceled());
+        assert!(!queue.is_empty(), "Canceling should not drain the queue");
+    }
+
+    #[test]
+    fn test_shutdown_ignores_jobs_pushed_afterwards() {
+        let queue = ArcQueue::new();
+        let paused = Arc::new(AtomicBool::new(true));
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let sink = processed.clone();
+        let requeue = queue.clone();
+        let worker = Worker::with_pause_flag(
+            0,
+            queue.clone(),
+            move |n: i32| {
+                sink.lock().unwrap().push(n);
+                // Without the snapshot this would keep the worker busy forever
+                requeue.push(n * 10);
+            },
+            fast_config(),
+            paused,
+        );
+
+        for n in 1..=3 {
+            queue.push(n);
+        }
+        worker.shutdown();
+
+        assert_eq!(*processed.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(queue.len(), 3, "Jobs pushed after shutdown stay queued");
+        assert!(worker.is_finished());
+    }
+
+    #[test]
+    fn test_idle_worker_is_not_busy() {
+        let queue: ArcQueue<i32> = ArcQueue::new();
+        let worker = Worker::new(0, queue, |_| {}, fast_config());
+
+        for _ in 0..50 {
+            assert!(!worker.is_busy(), "Polling an empty queue is not work");
+            sleep(Duration::from_millis(1));
+        }
+    }
 }
@@ -1,7 +1,7 @@
-use pooler::TaskQueue;
-use std::sync::{Arc, Mutex};
+use pooler::{TaskQueue, TaskQueueConfig, WorkerConfig};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[test]
 fn test_basic_task_execution() {
@@ -49,13 +49,13 @@ fn test_multiple_workers() {
 
 #[test]
 fn test_queue_empty_and_busy() {
-    let counter = Arc::new(Mutex::new(0));
-    let handler_counter = counter.clone();
+    let (started_sender, started) = mpsc::channel();
+    let (release, release_receiver) = mpsc::channel::<()>();
+    let release_receiver = Mutex::new(release_receiver);
 
     let queue = TaskQueue::new(1, move |num: usize| {
-        thread::sleep(Duration::from_millis(50)); // Simulate work
-        let mut count = handler_counter.lock().unwrap();
-        *count += num;
+        started_sender.send(num).unwrap();
+        release_receiver.lock().unwrap().recv().unwrap();
     });
 
     assert!(queue.is_empty(), "Queue should be empty initially");
@@ -63,14 +63,14 @@ fn test_queue_empty_and_busy() {
 
     queue.push(1);
 
-    // Give the worker a moment to start
-    thread::sleep(Duration::from_millis(10));
+    assert_eq!(started.recv().unwrap(), 1);
+    assert!(queue.is_empty(), "The item should have been taken");
     assert!(queue.is_busy(), "Queue should be busy while processing");
 
+    release.send(()).unwrap();
     queue.wait();
     assert!(!queue.is_busy(), "Queue should not be busy after waiting");
     assert!(queue.is_empty(), "Queue should be empty after processing");
-    assert_eq!(*counter.lock().unwrap(), 1);
 }
 
 #[test]
@@ -104,3 +104,52 @@ fn test_concurrent_pushes() {
     queue.wait();
     assert_eq!(*counter.lock().unwrap(), 50);
 }
+
+/// Returns how long an idle single-worker queue takes to pick up a pushed item.
+fn time_to_pick_up(config: TaskQueueConfig) -> Duration {
+    let (picked_up_sender, picked_up) = mpsc::channel();
+    let queue = TaskQueue::with_config(1, config, move |num: usize| {
+        picked_up_sender.send((num, Instant::now())).unwrap();
+    });
+
+    // Once the first item is done the worker polls the empty queue and goes to sleep
+    queue.push(1);
+    assert_eq!(picked_up.recv_timeout(Duration::from_secs(5)).unwrap().0, 1);
+    thread::sleep(Duration::from_millis(30));
+
+    let pushed_at = Instant::now();
+    queue.push(2);
+    let (num, picked_up_at) = picked_up.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(num, 2);
+    picked_up_at - pushed_at
+}
+
+fn config_with_idle_sleep(idle_sleep: Duration) -> TaskQueueConfig {
+    TaskQueueConfig {
+        worker: WorkerConfig {
+            idle_sleep,
+            ..WorkerConfig::default()
+        },
+        wait_interval: Duration::from_millis(1),
+    }
+}
+
+#[test]
+fn test_short_idle_sleep_picks_up_quickly() {
+    let elapsed = time_to_pick_up(config_with_idle_sleep(Duration::from_millis(1)));
+    assert!(
+        elapsed < Duration::from_millis(50),
+        "Item should be picked up within a few idle sleeps, took {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn test_long_idle_sleep_delays_pick_up() {
+    let elapsed = time_to_pick_up(config_with_idle_sleep(Duration::from_millis(200)));
+    assert!(
+        elapsed >= Duration::from_millis(100),
+        "Item should wait for the sleeping worker to wake up, took {:?}",
+        elapsed
+    );
+}
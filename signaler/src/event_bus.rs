@@ -1,6 +1,29 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 /// A type alias for event handler callbacks that can be safely shared between threads
 type Callback<T> = Box<dyn Fn(T) + Send + 'static>;
 
+/// Identifies a handler registered on an [`EventBus`], so it can later be removed.
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Copy, Clone, Debug)]
+pub struct HandlerId(usize);
+
+/// A registered handler along with the bookkeeping needed for one-shot handlers
+struct Handler<T> {
+    id: HandlerId,
+    callback: Callback<T>,
+    /// Whether the handler should only be called for the first event
+    once: bool,
+    /// Set once a one-shot handler has been called
+    fired: AtomicBool,
+}
+
+impl<T> Handler<T> {
+    /// Returns `true` if the handler is a one-shot handler that has already been called.
+    fn is_spent(&self) -> bool {
+        self.once && self.fired.load(Ordering::Acquire)
+    }
+}
+
 /// An event bus that enables decoupled communication between components through a publish-subscribe pattern.
 ///
 /// The `EventBus` provides a thread-safe mechanism for components to subscribe to and receive notifications
@@ -20,14 +43,19 @@ type Callback<T> = Box<dyn Fn(T) + Send + 'static>;
 /// let mut bus = EventBus::new();
 ///
 /// // Add a handler that processes events
-/// bus.add_handler(|value| println!("Received: {}", value));
+/// let handler = bus.add_handler(|value| println!("Received: {}", value));
 ///
 /// // Notify all handlers with an event
 /// bus.notify(42);
+///
+/// // Stop receiving events
+/// bus.remove_handler(handler);
 /// ```
 pub struct EventBus<T> {
-    /// Collection of registered event handlers
-    handlers: Vec<Callback<T>>,
+    /// Collection of registered event handlers, in registration order
+    handlers: Vec<Handler<T>>,
+    /// The ID given to the next registered handler
+    next_handler_id: usize,
 }
 
 impl<T> EventBus<T>
@@ -40,6 +68,7 @@ where
     pub fn new() -> Self {
         EventBus {
             handlers: Vec::new(),
+            next_handler_id: 0,
         }
     }
 
@@ -52,28 +81,110 @@ where
     ///
     /// * `handler` - A function that processes events of type `T`. Must be `Send` and `'static`
     ///   to ensure thread safety and proper lifetime management.
-    pub fn add_handler<F>(&mut self, handler: F)
+    ///
+    /// # Returns
+    ///
+    /// The ID of the handler, which can be passed to `remove_handler` to unregister it.
+    pub fn add_handler<F>(&mut self, handler: F) -> HandlerId
+    where
+        F: Fn(T) + Send + 'static,
+    {
+        self.register(Box::new(handler), false)
+    }
+
+    /// Registers an event handler that is only called for the next published event.
+    ///
+    /// After its first invocation the handler is treated as removed and is never
+    /// called again.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function that processes a single event of type `T`. Must be `Send`
+    ///   and `'static`.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the handler, which can be passed to `remove_handler` to unregister it
+    /// before it fires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::EventBus;
+    ///
+    /// let mut bus = EventBus::new();
+    /// bus.add_handler_once(|value: i32| println!("First event: {}", value));
+    ///
+    /// bus.notify(1); // Prints "First event: 1"
+    /// bus.notify(2); // The handler is not called
+    /// ```
+    pub fn add_handler_once<F>(&mut self, handler: F) -> HandlerId
     where
         F: Fn(T) + Send + 'static,
     {
-        self.handlers.push(Box::new(handler));
+        self.register(Box::new(handler), true)
+    }
+
+    /// Stores a handler under a fresh ID, dropping any one-shot handlers that have fired.
+    fn register(&mut self, callback: Callback<T>, once: bool) -> HandlerId {
+        self.handlers.retain(|handler| !handler.is_spent());
+
+        let id = HandlerId(self.next_handler_id);
+        self.next_handler_id += 1;
+        self.handlers.push(Handler {
+            id,
+            callback,
+            once,
+            fired: AtomicBool::new(false),
+        });
+        id
+    }
+
+    /// Unregisters a handler so it no longer receives events.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID returned by `add_handler` when the handler was registered.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the handler was found and removed, `false` if it wasn't registered.
+    pub fn remove_handler(&mut self, id: HandlerId) -> bool {
+        self.handlers.retain(|handler| !handler.is_spent());
+
+        let count = self.handlers.len();
+        self.handlers.retain(|handler| handler.id != id);
+        self.handlers.len() != count
     }
 
     /// Publishes an event to all registered handlers.
     ///
     /// Iterates through all registered handlers and calls each one with the provided event data.
-    /// The handlers are called synchronously in their registration order.
+    /// The handlers are called synchronously in their registration order. One-shot
+    /// handlers registered with `add_handler_once` are skipped once they have fired.
     ///
     /// # Arguments
     ///
     /// * `message` - The event data to be passed to each handler.
     pub fn notify(&self, message: T) {
         for handler in &self.handlers {
-            handler(message);
+            if handler.once && handler.fired.swap(true, Ordering::AcqRel) {
+                continue;
+            }
+            (handler.callback)(message);
         }
     }
 }
 
+impl<T> Default for EventBus<T>
+where
+    T: Copy,
+{
+    fn default() -> Self {
+        EventBus::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,4 +261,108 @@ mod tests {
 
         assert_eq!(*counter.lock().unwrap(), 3);
     }
+
+    #[test]
+    fn test_removed_handler_is_not_called() {
+        let mut bus = EventBus::new();
+        let removed = Arc::new(Mutex::new(0));
+        let kept = Arc::new(Mutex::new(0));
+        let removed_clone = removed.clone();
+        let kept_clone = kept.clone();
+
+        let id = bus.add_handler(move |_: i32| {
+            *removed_clone.lock().unwrap() += 1;
+        });
+        bus.add_handler(move |_: i32| {
+            *kept_clone.lock().unwrap() += 1;
+        });
+
+        bus.notify(1);
+        assert!(bus.remove_handler(id));
+        bus.notify(2);
+
+        assert_eq!(*removed.lock().unwrap(), 1);
+        assert_eq!(*kept.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_remove_unknown_handler() {
+        let mut bus = EventBus::new();
+        let id = bus.add_handler(|_: i32| {});
+
+        assert!(bus.remove_handler(id));
+        assert!(!bus.remove_handler(id), "Handler was already removed");
+        assert_eq!(bus.handlers.len(), 0);
+    }
+
+    #[test]
+    fn test_handler_ids_are_unique() {
+        let mut bus = EventBus::new();
+        let first = bus.add_handler(|_: i32| {});
+        bus.remove_handler(first);
+        let second = bus.add_handler(|_: i32| {});
+
+        assert_ne!(first, second, "IDs should not be reused");
+    }
+
+    #[test]
+    fn test_once_handler_fires_exactly_once() {
+        let mut bus = EventBus::new();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let calls_clone = calls.clone();
+
+        bus.add_handler_once(move |value: i32| {
+            calls_clone.lock().unwrap().push(value);
+        });
+
+        bus.notify(1);
+        bus.notify(2);
+        bus.notify(3);
+
+        assert_eq!(*calls.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_once_handler_does_not_affect_regular_handlers() {
+        let mut bus = EventBus::new();
+        let counter = Arc::new(Mutex::new(0));
+        let counter_clone = counter.clone();
+
+        bus.add_handler_once(|_: i32| {});
+        bus.add_handler(move |_: i32| {
+            *counter_clone.lock().unwrap() += 1;
+        });
+
+        bus.notify(1);
+        bus.notify(2);
+
+        assert_eq!(*counter.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_fired_once_handler_is_removed() {
+        let mut bus = EventBus::new();
+        let id = bus.add_handler_once(|_: i32| {});
+
+        bus.notify(1);
+
+        assert!(!bus.remove_handler(id), "Handler already removed itself");
+        assert_eq!(bus.handlers.len(), 0);
+    }
+
+    #[test]
+    fn test_once_handler_can_be_removed_before_firing() {
+        let mut bus = EventBus::new();
+        let counter = Arc::new(Mutex::new(0));
+        let counter_clone = counter.clone();
+
+        let id = bus.add_handler_once(move |_: i32| {
+            *counter_clone.lock().unwrap() += 1;
+        });
+
+        assert!(bus.remove_handler(id));
+        bus.notify(1);
+
+        assert_eq!(*counter.lock().unwrap(), 0);
+    }
 }
@@ -6,3 +6,6 @@ pub use message_queue::*;
 
 mod pipeline;
 pub use pipeline::*;
+
+mod typed_event_bus;
+pub use typed_event_bus::*;
@@ -3,6 +3,14 @@
 /// message propagation should stop (true) or continue (false).
 type Callback<T> = Box<dyn Fn(T) -> bool + Send + 'static>;
 
+/// Identifies a handler registered on a [`MessageQueue`], so it can later be removed.
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Copy, Clone, Debug)]
+pub struct MessageHandlerId(usize);
+
+/// Type alias for message filters. A filter returns `true` if the message should be
+/// delivered to the handlers.
+type Filter<T> = Box<dyn Fn(T) -> bool + Send + 'static>;
+
 /// A thread-safe message queue that supports multiple handlers with controlled message propagation.
 ///
 /// `MessageQueue` allows registration of multiple callback handlers that process messages of type T.
@@ -27,7 +35,12 @@ type Callback<T> = Box<dyn Fn(T) -> bool + Send + 'static>;
 /// ```
 #[derive(Default)]
 pub struct MessageQueue<T> {
-    handlers: Vec<Callback<T>>,
+    /// Registered handlers, in registration order
+    handlers: Vec<(MessageHandlerId, Callback<T>)>,
+    /// The ID given to the next registered handler
+    next_handler_id: usize,
+    /// Filters that every message must pass before reaching the handlers
+    filters: Vec<Filter<T>>,
 }
 
 impl<T> MessageQueue<T>
@@ -42,6 +55,8 @@ where
     pub fn new() -> Self {
         MessageQueue {
             handlers: Vec::new(),
+            next_handler_id: 0,
+            filters: Vec::new(),
         }
     }
 
@@ -60,19 +75,106 @@ where
     where
         F: Fn(T) -> bool + Send + 'static,
     {
-        self.handlers.push(Box::new(handler));
+        self.add_handler_with_id(handler);
+    }
+
+    /// Registers a new message handler and returns an ID that can be used to remove it.
+    ///
+    /// The handler behaves exactly as one registered with `add_handler`.
+    ///
+    /// # Parameters
+    ///
+    /// * `handler` - A function that will be called when messages are sent through the queue.
+    ///   The handler should return `true` to stop message propagation, or `false` to allow
+    ///   the message to continue to subsequent handlers.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the handler, which can be passed to `remove_handler`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::MessageQueue;
+    ///
+    /// let mut queue = MessageQueue::new();
+    /// let id = queue.add_handler_with_id(|msg: i32| msg < 0);
+    /// assert!(queue.remove_handler(id));
+    /// ```
+    pub fn add_handler_with_id<F>(&mut self, handler: F) -> MessageHandlerId
+    where
+        F: Fn(T) -> bool + Send + 'static,
+    {
+        let id = MessageHandlerId(self.next_handler_id);
+        self.next_handler_id += 1;
+        self.handlers.push((id, Box::new(handler)));
+        id
+    }
+
+    /// Unregisters a handler so it no longer receives messages.
+    ///
+    /// The remaining handlers keep their relative order, so propagation continues to
+    /// run through them in the order they were registered.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The ID returned by `add_handler_with_id` when the handler was registered.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the handler was found and removed, `false` otherwise.
+    pub fn remove_handler(&mut self, id: MessageHandlerId) -> bool {
+        let count = self.handlers.len();
+        self.handlers.retain(|(handler_id, _)| *handler_id != id);
+        self.handlers.len() != count
+    }
+
+    /// Registers a filter that decides which messages reach the handlers.
+    ///
+    /// Filters run before any handler is called. A message is only delivered if every
+    /// registered filter returns `true` for it; otherwise no handler sees the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `filter` - A function returning `true` if the message should be delivered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::MessageQueue;
+    ///
+    /// let mut queue = MessageQueue::new();
+    /// queue.add_filter(|msg: i32| msg > 0);
+    /// queue.add_handler(|msg: i32| {
+    ///     println!("Positive: {}", msg);
+    ///     false
+    /// });
+    ///
+    /// queue.notify(-1); // No handler is called
+    /// queue.notify(1); // Prints "Positive: 1"
+    /// ```
+    pub fn add_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(T) -> bool + Send + 'static,
+    {
+        self.filters.push(Box::new(filter));
     }
 
     /// Sends a message to all registered handlers in order of registration.
     ///
-    /// Message propagation continues until either all handlers have processed the message
-    /// or a handler returns true to stop propagation.
+    /// Messages rejected by any filter are dropped without calling any handler.
+    /// Otherwise, message propagation continues until either all handlers have processed
+    /// the message or a handler returns true to stop propagation.
     ///
     /// # Parameters
     ///
     /// * `message` - The message to send to all registered handlers.
     pub fn notify(&self, message: T) {
-        for handler in &self.handlers {
+        if !self.filters.iter().all(|filter| filter(message)) {
+            return;
+        }
+
+        for (_, handler) in &self.handlers {
             if handler(message) {
                 break;
             }
@@ -167,4 +269,111 @@ mod tests {
         });
         queue.notify(Message(42));
     }
+
+    #[test]
+    fn test_removing_stopping_handler_restores_propagation() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut queue = MessageQueue::new();
+
+        let stopper = queue.add_handler_with_id(|_: i32| true);
+
+        let counter_clone = counter.clone();
+        queue.add_handler(move |_: i32| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            false
+        });
+
+        queue.notify(1);
+        assert_eq!(counter.load(Ordering::SeqCst), 0, "Propagation was stopped");
+
+        assert!(queue.remove_handler(stopper));
+        queue.notify(2);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_removing_middle_handler_keeps_order() {
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut queue = MessageQueue::new();
+
+        let mut ids = Vec::new();
+        for index in 0..3 {
+            let order_clone = order.clone();
+            ids.push(queue.add_handler_with_id(move |_: i32| {
+                order_clone.lock().unwrap().push(index);
+                false
+            }));
+        }
+
+        assert!(queue.remove_handler(ids[1]));
+        queue.notify(42);
+
+        assert_eq!(*order.lock().unwrap(), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_remove_unknown_handler() {
+        let mut queue = MessageQueue::new();
+        let id = queue.add_handler_with_id(|_: i32| false);
+
+        assert!(queue.remove_handler(id));
+        assert!(!queue.remove_handler(id), "Handler was already removed");
+        assert!(queue.handlers.is_empty());
+    }
+
+    #[test]
+    fn test_message_passing_all_filters_reaches_handlers() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut queue = MessageQueue::new();
+        queue.add_filter(|msg: i32| msg > 0);
+        queue.add_filter(|msg: i32| msg % 2 == 0);
+
+        let counter_clone = counter.clone();
+        queue.add_handler(move |_: i32| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            false
+        });
+
+        queue.notify(4);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_message_failing_one_filter_reaches_no_handlers() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let mut queue = MessageQueue::new();
+        queue.add_filter(|msg: i32| msg > 0);
+        queue.add_filter(|msg: i32| msg % 2 == 0);
+
+        for _ in 0..2 {
+            let counter_clone = counter.clone();
+            queue.add_handler(move |_: i32| {
+                counter_clone.fetch_add(1, Ordering::SeqCst);
+                false
+            });
+        }
+
+        queue.notify(3);
+        queue.notify(-2);
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_filters_do_not_block_other_messages() {
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut queue = MessageQueue::new();
+        queue.add_filter(|msg: i32| msg != 2);
+
+        let received_clone = received.clone();
+        queue.add_handler(move |msg: i32| {
+            received_clone.lock().unwrap().push(msg);
+            false
+        });
+
+        for msg in 1..=3 {
+            queue.notify(msg);
+        }
+
+        assert_eq!(*received.lock().unwrap(), vec![1, 3]);
+    }
 }
@@ -30,6 +30,7 @@
 ///
 pub struct Pipeline<TInput: 'static, TOutput: 'static> {
     transformer: Box<dyn Fn(TInput) -> TOutput>,
+    step_count: usize,
 }
 
 impl<T> Pipeline<T, T> {
@@ -53,10 +54,17 @@ impl<T> Pipeline<T, T> {
     pub fn new() -> Self {
         Pipeline {
             transformer: Box::new(|x| x),
+            step_count: 0,
         }
     }
 }
 
+impl<T> Default for Pipeline<T, T> {
+    fn default() -> Self {
+        Pipeline::new()
+    }
+}
+
 impl<TInput, TOutput> Pipeline<TInput, TOutput> {
     /// Executes the pipeline transformation with the provided input value.
     ///
@@ -85,6 +93,77 @@ impl<TInput, TOutput> Pipeline<TInput, TOutput> {
         (self.transformer)(input)
     }
 
+    /// Returns the number of steps that have been chained onto the pipeline.
+    ///
+    /// A new pipeline has no steps, and each combinator such as `map`, `filter`
+    /// or `and_then` adds one. This is intended for debugging and introspection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::Pipeline;
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .map(|x: i32| x * 2)
+    ///     .filter(|&x| x > 5);
+    ///
+    /// assert_eq!(pipeline.step_count(), 2);
+    /// ```
+    pub fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    /// Chains another pipeline onto the end of this one.
+    ///
+    /// Executing the composed pipeline is the same as executing this pipeline
+    /// and then passing its result to `other`. This allows reusable
+    /// sub-pipelines to be built separately and snapped together. The step
+    /// count of the result is the sum of both pipelines' step counts.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `TNext` - The output type of the other pipeline
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - A pipeline whose input type is this pipeline's output type
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Pipeline` from this pipeline's input to `other`'s output
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::Pipeline;
+    ///
+    /// let parse = Pipeline::new().map(|s: &str| s.len());
+    /// let describe = Pipeline::new().map(|n: usize| format!("{} chars", n));
+    ///
+    /// let pipeline = parse.compose(describe);
+    /// assert_eq!(pipeline.execute("hello"), "5 chars");
+    /// ```
+    pub fn compose<TNext>(self, other: Pipeline<TOutput, TNext>) -> Pipeline<TInput, TNext> {
+        let step_count = self.step_count + other.step_count;
+        Pipeline {
+            transformer: Box::new(move |input| other.execute(self.execute(input))),
+            step_count,
+        }
+    }
+
+    /// Appends a step to the pipeline, counting it towards `step_count`.
+    fn then<TNext, F>(self, f: F) -> Pipeline<TInput, TNext>
+    where
+        F: 'static + Fn(TOutput) -> TNext,
+        TNext: 'static,
+    {
+        let step_count = self.step_count + 1;
+        Pipeline {
+            transformer: Box::new(move |input| f((self.transformer)(input))),
+            step_count,
+        }
+    }
+
     /// Adds a new transformation step to the pipeline.
     ///
     /// This method allows you to chain a new transformation function that converts
@@ -120,12 +199,7 @@ impl<TInput, TOutput> Pipeline<TInput, TOutput> {
         F: 'static + Fn(TOutput) -> TNext,
         TNext: 'static,
     {
-        Pipeline {
-            transformer: Box::new(move |input| {
-                let intermediate = (self.transformer)(input);
-                f(intermediate)
-            }),
-        }
+        self.then(f)
     }
 
     /// Chains a function that returns an Option, enabling optional transformations.
@@ -164,12 +238,7 @@ impl<TInput, TOutput> Pipeline<TInput, TOutput> {
         F: 'static + Fn(TOutput) -> Option<TNext>,
         TNext: 'static,
     {
-        Pipeline {
-            transformer: Box::new(move |input| {
-                let intermediate = (self.transformer)(input);
-                f(intermediate)
-            }),
-        }
+        self.then(f)
     }
 
     /// Adds a filter predicate to the pipeline, converting it to handle Options.
@@ -205,22 +274,140 @@ impl<TInput, TOutput> Pipeline<TInput, TOutput> {
     where
         F: 'static + Fn(&TOutput) -> bool,
     {
-        Pipeline {
-            transformer: Box::new(move |input| {
-                let result = (self.transformer)(input);
-                if predicate(&result) {
-                    Some(result)
-                } else {
-                    None
-                }
-            }),
-        }
+        self.then(move |result| {
+            if predicate(&result) {
+                Some(result)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Inspects the current value without changing it.
+    ///
+    /// The function is called with a reference to the value each time the
+    /// pipeline is executed, and the value is then passed on unchanged. This is
+    /// useful for logging or debugging intermediate values in a chain.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The type of the inspection function
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that receives a reference to the current value
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Pipeline` with the same output type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::Pipeline;
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .map(|x: i32| x * 2)
+    ///     .tap(|x| println!("doubled: {}", x))
+    ///     .map(|x| x + 1);
+    ///
+    /// assert_eq!(pipeline.execute(20), 41);
+    /// ```
+    pub fn tap<F>(self, f: F) -> Pipeline<TInput, TOutput>
+    where
+        F: 'static + Fn(&TOutput),
+    {
+        self.then(move |result| {
+            f(&result);
+            result
+        })
+    }
+
+    /// Chains a fallible transformation that returns a `Result`.
+    ///
+    /// This is the `Result` counterpart to `and_then`. Follow it with
+    /// `try_and_then` to keep chaining fallible steps that stop at the first error.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `TNext` - The type produced when the transformation succeeds
+    /// * `Err` - The error type produced when the transformation fails
+    /// * `F` - The type of the transformation function
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that transforms `TOutput` into `Result<TNext, Err>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Pipeline` that produces `Result` values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::Pipeline;
+    ///
+    /// let pipeline = Pipeline::new().try_map(|s: &str| s.parse::<i32>());
+    ///
+    /// assert_eq!(pipeline.execute("42"), Ok(42));
+    /// assert!(pipeline.execute("forty-two").is_err());
+    /// ```
+    pub fn try_map<TNext, Err, F>(self, f: F) -> Pipeline<TInput, Result<TNext, Err>>
+    where
+        F: 'static + Fn(TOutput) -> Result<TNext, Err>,
+        TNext: 'static,
+        Err: 'static,
+    {
+        self.then(f)
+    }
+}
+
+impl<TInput, TOutput, Err> Pipeline<TInput, Result<TOutput, Err>> {
+    /// Chains a fallible transformation onto a pipeline that already produces a `Result`.
+    ///
+    /// The function is only called when the previous step succeeded. An `Err`
+    /// from an earlier step is passed through unchanged and skips the function.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `TNext` - The type produced when the transformation succeeds
+    /// * `F` - The type of the transformation function
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that transforms `TOutput` into `Result<TNext, Err>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Pipeline` that produces `Result` values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use signaler::Pipeline;
+    ///
+    /// let pipeline = Pipeline::new()
+    ///     .try_map(|s: &str| s.parse::<i32>().map_err(|_| "not a number"))
+    ///     .try_and_then(|x| if x >= 0 { Ok(x as u32) } else { Err("negative") });
+    ///
+    /// assert_eq!(pipeline.execute("7"), Ok(7));
+    /// assert_eq!(pipeline.execute("-7"), Err("negative"));
+    /// assert_eq!(pipeline.execute("seven"), Err("not a number"));
+    /// ```
+    pub fn try_and_then<TNext, F>(self, f: F) -> Pipeline<TInput, Result<TNext, Err>>
+    where
+        F: 'static + Fn(TOutput) -> Result<TNext, Err>,
+        TNext: 'static,
+    {
+        self.then(move |result: Result<TOutput, Err>| result.and_then(&f))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
 
     #[test]
     fn test_new_pipeline_identity() {
@@ -283,7 +470,124 @@ mod tests {
             .map(|s| s.len())
             .map(|len| len > 1);
 
-        assert_eq!(pipeline.execute(42), true);
-        assert_eq!(pipeline.execute(5), false);
+        assert!(pipeline.execute(42));
+        assert!(!pipeline.execute(5));
+    }
+
+    #[test]
+    fn test_tap_passes_value_through() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let pipeline = Pipeline::new()
+            .map(|x: i32| x * 2)
+            .tap(move |x| seen_clone.borrow_mut().push(*x));
+
+        assert_eq!(pipeline.execute(4), 8);
+        assert_eq!(pipeline.execute(5), 10);
+        assert_eq!(*seen.borrow(), vec![8, 10]);
+    }
+
+    #[test]
+    fn test_tap_called_once_per_execute() {
+        let calls = Rc::new(Cell::new(0));
+        let calls_clone = calls.clone();
+        let pipeline = Pipeline::new()
+            .map(|x: i32| x.to_string())
+            .tap(move |_| calls_clone.set(calls_clone.get() + 1));
+
+        assert_eq!(calls.get(), 0, "Tap should not run before execute");
+        pipeline.execute(1);
+        assert_eq!(calls.get(), 1);
+        pipeline.execute(2);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_try_map_ok_flows_through() {
+        let pipeline = Pipeline::new()
+            .try_map(|s: String| s.parse::<i32>())
+            .try_and_then(|x| Ok(x * 2));
+
+        assert_eq!(pipeline.execute("21".to_string()), Ok(42));
+    }
+
+    #[test]
+    fn test_try_and_then_stops_at_first_error() {
+        let calls = Rc::new(Cell::new(0));
+        let calls_clone = calls.clone();
+        let pipeline = Pipeline::new()
+            .try_map(|x: i32| if x > 0 { Ok(x) } else { Err("not positive") })
+            .try_and_then(move |x| {
+                calls_clone.set(calls_clone.get() + 1);
+                if x < 100 {
+                    Ok(x * 10)
+                } else {
+                    Err("too large")
+                }
+            })
+            .try_and_then(|x| Ok::<_, &str>(x + 1));
+
+        assert_eq!(pipeline.execute(5), Ok(51));
+        assert_eq!(pipeline.execute(500), Err("too large"));
+        assert_eq!(calls.get(), 2);
+
+        assert_eq!(pipeline.execute(-5), Err("not positive"));
+        assert_eq!(calls.get(), 2, "Later steps should not run after an error");
+    }
+
+    #[test]
+    fn test_step_count_new_pipeline() {
+        let pipeline = Pipeline::<i32, i32>::new();
+        assert_eq!(pipeline.step_count(), 0);
+        assert_eq!(Pipeline::<i32, i32>::default().step_count(), 0);
+    }
+
+    #[test]
+    fn test_step_count_increments_per_step() {
+        let pipeline = Pipeline::new().map(|x: i32| x + 1);
+        assert_eq!(pipeline.step_count(), 1);
+
+        let pipeline = pipeline.map(|x| x * 2);
+        assert_eq!(pipeline.step_count(), 2);
+
+        let pipeline = pipeline.filter(|&x| x > 0);
+        assert_eq!(pipeline.step_count(), 3);
+
+        let pipeline = pipeline.tap(|_| {}).and_then(|x| x);
+        assert_eq!(pipeline.step_count(), 5);
+    }
+
+    #[test]
+    fn test_step_count_fallible_steps() {
+        let pipeline = Pipeline::new()
+            .try_map(|s: &str| s.parse::<i32>())
+            .try_and_then(|x| Ok(x + 1));
+        assert_eq!(pipeline.step_count(), 2);
+        assert_eq!(pipeline.execute("1"), Ok(2));
+    }
+
+    #[test]
+    fn test_compose_matches_chained_steps() {
+        let first = Pipeline::new().map(|x: i32| x * 3).filter(|&x| x % 2 == 0);
+        let second = Pipeline::new().map(|x: Option<i32>| x.map(|v| v.to_string()));
+        let composed = first.compose(second);
+
+        let chained = Pipeline::new()
+            .map(|x: i32| x * 3)
+            .filter(|&x| x % 2 == 0)
+            .map(|x: Option<i32>| x.map(|v| v.to_string()));
+
+        for input in -3..=3 {
+            assert_eq!(composed.execute(input), chained.execute(input));
+        }
+        assert_eq!(composed.step_count(), chained.step_count());
+    }
+
+    #[test]
+    fn test_compose_with_identity() {
+        let pipeline = Pipeline::new().map(|x: i32| x + 1).compose(Pipeline::new());
+
+        assert_eq!(pipeline.execute(1), 2);
+        assert_eq!(pipeline.step_count(), 1);
     }
 }
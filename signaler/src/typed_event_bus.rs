@@ -0,0 +1,175 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+/// A type-erased event handler. The event is passed as `&dyn Any` and downcast back
+/// to its concrete type by the wrapper created in `subscribe`.
+type Callback = Box<dyn Fn(&dyn Any) + Send + 'static>;
+
+/// An event bus that carries events of many different types.
+///
+/// Where `EventBus<T>` delivers every event to every handler, `TypedEventBus` routes each
+/// event by its type: a handler subscribed to events of type `E` is only called when an
+/// `E` is published. This lets a single bus carry several unrelated event types without
+/// wrapping them in an enum.
+///
+/// # Examples
+///
+/// ```
+/// use signaler::TypedEventBus;
+///
+/// #[derive(Clone, Copy)]
+/// struct Clicked { x: i32, y: i32 }
+///
+/// #[derive(Clone, Copy)]
+/// struct KeyPressed(char);
+///
+/// let mut bus = TypedEventBus::new();
+/// bus.subscribe(|event: Clicked| println!("Clicked at {}, {}", event.x, event.y));
+/// bus.subscribe(|event: KeyPressed| println!("Pressed {}", event.0));
+///
+/// // Only the `Clicked` handler is called
+/// bus.publish(Clicked { x: 10, y: 20 });
+/// ```
+#[derive(Default)]
+pub struct TypedEventBus {
+    /// Registered handlers, keyed by the type of event they accept
+    handlers: HashMap<TypeId, Vec<Callback>>,
+}
+
+impl TypedEventBus {
+    /// Creates a new event bus with no handlers.
+    pub fn new() -> Self {
+        TypedEventBus {
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Registers a handler for events of type `E`.
+    ///
+    /// Handlers for the same event type are called in registration order.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The type of event the handler accepts.
+    /// * `F` - The handler function type.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function called with every published event of type `E`.
+    pub fn subscribe<E, F>(&mut self, handler: F)
+    where
+        E: 'static + Copy,
+        F: Fn(E) + Send + 'static,
+    {
+        let callback: Callback = Box::new(move |event: &dyn Any| {
+            if let Some(event) = event.downcast_ref::<E>() {
+                handler(*event);
+            }
+        });
+        self.handlers
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(callback);
+    }
+
+    /// Publishes an event to every handler subscribed to its type.
+    ///
+    /// Handlers subscribed to other event types are not called.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to deliver.
+    pub fn publish<E>(&self, event: E)
+    where
+        E: 'static + Copy,
+    {
+        if let Some(handlers) = self.handlers.get(&TypeId::of::<E>()) {
+            for handler in handlers {
+                handler(&event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct EventA(i32);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct EventB(i32);
+
+    #[test]
+    fn test_new_bus_has_no_handlers() {
+        let bus = TypedEventBus::new();
+        assert!(bus.handlers.is_empty());
+    }
+
+    #[test]
+    fn test_publish_reaches_matching_handler() {
+        let mut bus = TypedEventBus::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let received_clone = received.clone();
+
+        bus.subscribe(move |event: EventA| {
+            received_clone.lock().unwrap().push(event);
+        });
+
+        bus.publish(EventA(1));
+        bus.publish(EventA(2));
+
+        assert_eq!(*received.lock().unwrap(), vec![EventA(1), EventA(2)]);
+    }
+
+    #[test]
+    fn test_handlers_do_not_fire_for_other_types() {
+        let mut bus = TypedEventBus::new();
+        let a_calls = Arc::new(Mutex::new(0));
+        let b_calls = Arc::new(Mutex::new(0));
+        let a_clone = a_calls.clone();
+        let b_clone = b_calls.clone();
+
+        bus.subscribe(move |_: EventA| {
+            *a_clone.lock().unwrap() += 1;
+        });
+        bus.subscribe(move |_: EventB| {
+            *b_clone.lock().unwrap() += 1;
+        });
+
+        bus.publish(EventB(7));
+
+        assert_eq!(
+            *a_calls.lock().unwrap(),
+            0,
+            "Type A handler should not fire"
+        );
+        assert_eq!(*b_calls.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_handlers_called_in_registration_order() {
+        let mut bus = TypedEventBus::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        for index in 0..3 {
+            let order_clone = order.clone();
+            bus.subscribe(move |_: EventA| {
+                order_clone.lock().unwrap().push(index);
+            });
+        }
+
+        bus.publish(EventA(0));
+
+        assert_eq!(*order.lock().unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_publish_without_handlers() {
+        let bus = TypedEventBus::new();
+        bus.publish(EventA(1));
+        bus.publish(42u8);
+    }
+}
@@ -1,2 +0,0 @@
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct Date;
@@ -1,8 +1,291 @@
-use crate::{Date, Time, TimeZone};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+use crate::{
+    calendar::{civil_from_days, MILLIS_PER_SECOND, SECONDS_PER_DAY},
+    Timezone,
+};
+
+/// A point in time, stored as milliseconds since the Unix epoch (UTC), along with
+/// the time zone it should be displayed in.
+///
+/// Comparisons look only at the instant, so two date-times displayed in different
+/// time zones are equal if they represent the same moment. The default value is
+/// the Unix epoch, `1970-01-01T00:00:00Z`.
+#[derive(Clone, Copy, Default, Debug)]
 pub struct DateTime {
-    date: Date,
-    time: Time,
-    timezone: TimeZone,
+    /// Milliseconds since `1970-01-01T00:00:00Z`
+    pub(crate) timestamp: i64,
+    /// The zone the date and time components are displayed in
+    pub(crate) timezone: Timezone,
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp.cmp(&other.timestamp)
+    }
+}
+
+impl Hash for DateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.timestamp.hash(state);
+    }
+}
+
+/// The calendar and clock fields of a `DateTime`, in its own time zone.
+pub(crate) struct Fields {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) millisecond: u16,
+}
+
+impl DateTime {
+    /// Splits the timestamp into calendar and clock fields, shifted into the
+    /// date-time's time zone.
+    pub(crate) fn fields(&self) -> Fields {
+        let local = self.timestamp + self.timezone.offset_seconds() * MILLIS_PER_SECOND;
+        let seconds = local.div_euclid(MILLIS_PER_SECOND);
+        let millisecond = local.rem_euclid(MILLIS_PER_SECOND) as u16;
+        let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+        let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+        Fields {
+            year,
+            month,
+            day,
+            hour: (second_of_day / 3_600) as u8,
+            minute: (second_of_day % 3_600 / 60) as u8,
+            second: (second_of_day % 60) as u8,
+            millisecond,
+        }
+    }
+
+    /// Returns the time zone the date-time is displayed in.
+    pub fn timezone(&self) -> Timezone {
+        self.timezone
+    }
+
+    /// Returns the same instant displayed in another time zone.
+    ///
+    /// Only the date and time components change; the result compares equal to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timer::{DateTime, Timezone};
+    ///
+    /// let utc = DateTime::from_iso8601("2024-01-01T12:00:00Z").unwrap();
+    /// let tokyo = utc.with_timezone(Timezone::FixedOffset(9 * 60));
+    ///
+    /// assert_eq!(tokyo.to_iso8601(), "2024-01-01T21:00:00+09:00");
+    /// assert_eq!(tokyo, utc);
+    /// ```
+    pub fn with_timezone(&self, timezone: Timezone) -> DateTime {
+        DateTime {
+            timestamp: self.timestamp,
+            timezone,
+        }
+    }
+
+    /// Returns the year in the date-time's time zone.
+    pub fn year(&self) -> i32 {
+        self.fields().year
+    }
+
+    /// Returns the month, `1` to `12`, in the date-time's time zone.
+    pub fn month(&self) -> u8 {
+        self.fields().month
+    }
+
+    /// Returns the day of the month, `1` to `31`, in the date-time's time zone.
+    pub fn day(&self) -> u8 {
+        self.fields().day
+    }
+
+    /// Returns the hour, `0` to `23`, in the date-time's time zone.
+    pub fn hour(&self) -> u8 {
+        self.fields().hour
+    }
+
+    /// Returns the minute, `0` to `59`, in the date-time's time zone.
+    pub fn minute(&self) -> u8 {
+        self.fields().minute
+    }
+
+    /// Returns the second, `0` to `59`.
+    pub fn second(&self) -> u8 {
+        self.fields().second
+    }
+
+    /// Returns the millisecond within the second, `0` to `999`.
+    pub fn millisecond(&self) -> u16 {
+        self.fields().millisecond
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Duration;
+    use std::collections::BTreeMap;
+
+    fn parse(s: &str) -> DateTime {
+        DateTime::from_iso8601(s).unwrap()
+    }
+
+    #[test]
+    fn test_accessors_utc() {
+        let datetime =
+            DateTime::from_iso8601("2024-07-04T09:08:07Z").unwrap() + Duration::from_millis(654);
+
+        assert_eq!(datetime.year(), 2024);
+        assert_eq!(datetime.month(), 7);
+        assert_eq!(datetime.day(), 4);
+        assert_eq!(datetime.hour(), 9);
+        assert_eq!(datetime.minute(), 8);
+        assert_eq!(datetime.second(), 7);
+        assert_eq!(datetime.millisecond(), 654);
+    }
+
+    #[test]
+    fn test_accessors_near_dst_boundary() {
+        // One second before clocks went forward in New York, at the fixed EST offset
+        let datetime = DateTime::from_iso8601("2024-03-10T01:59:59-05:00").unwrap();
+
+        assert_eq!(datetime.year(), 2024);
+        assert_eq!(datetime.month(), 3);
+        assert_eq!(datetime.day(), 10);
+        assert_eq!(datetime.hour(), 1);
+        assert_eq!(datetime.minute(), 59);
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(datetime.millisecond(), 0);
+
+        // A fixed offset doesn't observe DST, so the hour advances normally
+        let next = datetime + Duration::from_seconds(1);
+        assert_eq!((next.hour(), next.minute(), next.second()), (2, 0, 0));
+    }
+
+    #[test]
+    fn test_accessors_use_local_date() {
+        // Still the previous day in UTC
+        let datetime = DateTime::from_iso8601("2024-01-01T03:00:00+09:00").unwrap();
+
+        assert_eq!(datetime.year(), 2024);
+        assert_eq!(datetime.month(), 1);
+        assert_eq!(datetime.day(), 1);
+        assert_eq!(datetime.hour(), 3);
+    }
+
+    #[test]
+    fn test_accessors_before_epoch() {
+        let datetime = DateTime::default() - Duration::from_millis(1);
+
+        assert_eq!(datetime.year(), 1969);
+        assert_eq!(datetime.month(), 12);
+        assert_eq!(datetime.day(), 31);
+        assert_eq!(datetime.hour(), 23);
+        assert_eq!(datetime.minute(), 59);
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(datetime.millisecond(), 999);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut datetimes: Vec<DateTime> = [
+            "2024-06-01T00:00:00Z",
+            "1999-12-31T23:59:59Z",
+            "2024-05-31T23:00:00-02:00",
+            "2024-01-01T00:00:00Z",
+        ]
+        .into_iter()
+        .map(parse)
+        .collect();
+
+        datetimes.sort();
+
+        let sorted: Vec<String> = datetimes.iter().map(DateTime::to_iso8601).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                "1999-12-31T23:59:59Z",
+                "2024-01-01T00:00:00Z",
+                "2024-06-01T00:00:00Z",
+                "2024-05-31T23:00:00-02:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_same_instant_in_different_timezones_is_equal() {
+        let utc = parse("2024-01-01T00:00:00Z");
+        let india = parse("2024-01-01T05:30:00+05:30");
+
+        assert_eq!(utc, india);
+        assert_eq!(utc.cmp(&india), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_different_instants_are_not_equal() {
+        let first = parse("2024-01-01T00:00:00Z");
+        let second = first + Duration::from_millis(1);
+
+        assert_ne!(first, second);
+        assert!(first < second);
+        assert!(second > first);
+    }
+
+    #[test]
+    fn test_btree_map_key() {
+        let mut events = BTreeMap::new();
+        events.insert(parse("2024-03-01T00:00:00Z"), "third");
+        events.insert(parse("2024-01-01T00:00:00Z"), "first");
+        events.insert(parse("2024-02-01T00:00:00+01:00"), "second");
+
+        let order: Vec<&str> = events.values().copied().collect();
+        assert_eq!(order, vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn test_with_timezone_shifts_components() {
+        let utc = parse("2024-01-01T20:00:00Z");
+        let india = utc.with_timezone(Timezone::FixedOffset(5 * 60 + 30));
+
+        assert_eq!(india.timezone(), Timezone::FixedOffset(330));
+        assert_eq!((india.day(), india.hour(), india.minute()), (2, 1, 30));
+        assert_eq!(india, utc, "The instant is unchanged");
+        assert_eq!(
+            india.with_timezone(Timezone::UTC).to_iso8601(),
+            "2024-01-01T20:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_with_negative_timezone() {
+        let utc = parse("2024-01-01T02:15:00Z");
+        let local = utc.with_timezone(Timezone::FixedOffset(-3 * 60));
+
+        assert_eq!((local.day(), local.hour(), local.minute()), (31, 23, 15));
+        assert_eq!(local.month(), 12);
+        assert_eq!(local.year(), 2023);
+    }
 }
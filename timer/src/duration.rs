@@ -0,0 +1,273 @@
+use std::ops::{Add, Neg, Sub};
+
+use crate::{calendar::MILLIS_PER_SECOND, DateTime};
+
+/// A signed span of time with millisecond precision.
+///
+/// # Examples
+///
+/// ```
+/// use timer::{DateTime, Duration};
+///
+/// let start = DateTime::from_iso8601("2024-02-28T12:00:00Z").unwrap();
+/// let end = start + Duration::from_days(2);
+///
+/// assert_eq!(end.to_iso8601(), "2024-03-01T12:00:00Z");
+/// assert_eq!(end - start, Duration::from_hours(48));
+/// assert_eq!(start - end, -Duration::from_hours(48));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct Duration {
+    /// The length of the span in milliseconds
+    millis: u64,
+    /// Whether the span points backwards in time; always `false` for a zero span
+    negative: bool,
+}
+
+impl Duration {
+    /// A span of no time.
+    pub const ZERO: Duration = Duration {
+        millis: 0,
+        negative: false,
+    };
+
+    pub fn from_millis(millis: u64) -> Self {
+        Duration {
+            millis,
+            negative: false,
+        }
+    }
+
+    /// Creates a span of the given number of seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span doesn't fit in a `u64` of milliseconds.
+    pub fn from_seconds(seconds: u64) -> Self {
+        Duration::from_millis(seconds.checked_mul(1_000).expect("Duration overflowed"))
+    }
+
+    /// Creates a span of the given number of minutes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span doesn't fit in a `u64` of milliseconds.
+    pub fn from_minutes(minutes: u64) -> Self {
+        Duration::from_seconds(minutes.checked_mul(60).expect("Duration overflowed"))
+    }
+
+    /// Creates a span of the given number of hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span doesn't fit in a `u64` of milliseconds.
+    pub fn from_hours(hours: u64) -> Self {
+        Duration::from_minutes(hours.checked_mul(60).expect("Duration overflowed"))
+    }
+
+    /// Creates a span of the given number of days.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span doesn't fit in a `u64` of milliseconds.
+    pub fn from_days(days: u64) -> Self {
+        Duration::from_hours(days.checked_mul(24).expect("Duration overflowed"))
+    }
+
+    /// Returns the length of the span in milliseconds, ignoring its sign.
+    pub fn as_millis(&self) -> u64 {
+        self.millis
+    }
+
+    /// Returns the length of the span in whole seconds, ignoring its sign.
+    pub fn as_seconds(&self) -> u64 {
+        self.millis / MILLIS_PER_SECOND as u64
+    }
+
+    /// Returns true if the span points backwards in time.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the span as a signed number of milliseconds.
+    fn signed_millis(&self) -> i128 {
+        if self.negative {
+            -i128::from(self.millis)
+        } else {
+            i128::from(self.millis)
+        }
+    }
+
+    /// Builds a span from a signed number of milliseconds.
+    fn from_signed_millis(millis: i128) -> Self {
+        Duration {
+            millis: u64::try_from(millis.unsigned_abs()).expect("Duration overflowed"),
+            negative: millis < 0,
+        }
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Self::Output {
+        Duration::from_signed_millis(-self.signed_millis())
+    }
+}
+
+impl DateTime {
+    /// Moves the timestamp by a signed number of milliseconds, keeping the time zone.
+    fn shifted(self, millis: i128) -> DateTime {
+        let timestamp = i128::from(self.timestamp) + millis;
+        DateTime {
+            timestamp: i64::try_from(timestamp).expect("DateTime overflowed"),
+            timezone: self.timezone,
+        }
+    }
+}
+
+impl Add<Duration> for DateTime {
+    type Output = DateTime;
+
+    /// Returns the date-time `duration` later, in the same time zone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is outside the range of `DateTime`.
+    fn add(self, duration: Duration) -> Self::Output {
+        self.shifted(duration.signed_millis())
+    }
+}
+
+impl Sub<Duration> for DateTime {
+    type Output = DateTime;
+
+    /// Returns the date-time `duration` earlier, in the same time zone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is outside the range of `DateTime`.
+    fn sub(self, duration: Duration) -> Self::Output {
+        self.shifted(-duration.signed_millis())
+    }
+}
+
+impl Sub<DateTime> for DateTime {
+    type Output = Duration;
+
+    /// Returns the time elapsed from `other` to `self`, negative if `other` is later.
+    /// Time zones don't affect the result.
+    fn sub(self, other: DateTime) -> Self::Output {
+        Duration::from_signed_millis(i128::from(self.timestamp) - i128::from(other.timestamp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> DateTime {
+        DateTime::from_iso8601(s).unwrap()
+    }
+
+    #[test]
+    fn test_add_day_to_last_day_of_each_month() {
+        let cases = [
+            ("2023-01-31", "2023-02-01"),
+            ("2023-02-28", "2023-03-01"),
+            ("2024-02-28", "2024-02-29"),
+            ("2024-02-29", "2024-03-01"),
+            ("2023-03-31", "2023-04-01"),
+            ("2023-04-30", "2023-05-01"),
+            ("2023-05-31", "2023-06-01"),
+            ("2023-06-30", "2023-07-01"),
+            ("2023-07-31", "2023-08-01"),
+            ("2023-08-31", "2023-09-01"),
+            ("2023-09-30", "2023-10-01"),
+            ("2023-10-31", "2023-11-01"),
+            ("2023-11-30", "2023-12-01"),
+            ("2023-12-31", "2024-01-01"),
+        ];
+        for (day, next) in cases {
+            let datetime = parse(&format!("{}T10:00:00Z", day));
+            assert_eq!(
+                (datetime + Duration::from_days(1)).to_iso8601(),
+                format!("{}T10:00:00Z", next)
+            );
+        }
+    }
+
+    #[test]
+    fn test_subtract_second_from_midnight() {
+        let midnight = parse("2024-01-01T00:00:00Z");
+        assert_eq!(
+            (midnight - Duration::from_seconds(1)).to_iso8601(),
+            "2023-12-31T23:59:59Z"
+        );
+
+        let epoch = DateTime::default();
+        assert_eq!(
+            (epoch - Duration::from_seconds(1)).to_iso8601(),
+            "1969-12-31T23:59:59Z"
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_keeps_timezone() {
+        let datetime = parse("2024-03-31T23:30:00+02:00");
+        assert_eq!(
+            (datetime + Duration::from_minutes(45)).to_iso8601(),
+            "2024-04-01T00:15:00+02:00"
+        );
+    }
+
+    #[test]
+    fn test_adding_negative_duration() {
+        let datetime = parse("2024-03-01T00:00:00Z");
+        assert_eq!(
+            (datetime + -Duration::from_days(1)).to_iso8601(),
+            "2024-02-29T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_duration_between_instants() {
+        let start = parse("2023-12-31T22:00:00Z");
+        let end = parse("2024-01-02T01:30:15Z");
+
+        let elapsed = end - start;
+        assert_eq!(elapsed.as_millis(), (27 * 3_600 + 30 * 60 + 15) * 1_000);
+        assert!(!elapsed.is_negative());
+
+        let backwards = start - end;
+        assert_eq!(backwards.as_millis(), elapsed.as_millis());
+        assert!(backwards.is_negative());
+    }
+
+    #[test]
+    fn test_duration_between_timezones() {
+        let utc = parse("2024-01-01T00:00:00Z");
+        let india = parse("2024-01-01T05:30:00+05:30");
+
+        assert_eq!(india - utc, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_negative_zero_is_zero() {
+        assert_eq!(-Duration::ZERO, Duration::ZERO);
+        assert!(!(-Duration::ZERO).is_negative());
+    }
+
+    #[test]
+    fn test_unit_constructors() {
+        assert_eq!(Duration::from_days(1).as_millis(), 86_400_000);
+        assert_eq!(Duration::from_hours(1).as_seconds(), 3_600);
+        assert_eq!(Duration::from_minutes(2), Duration::from_seconds(120));
+    }
+
+    #[test]
+    #[should_panic(expected = "Duration overflowed")]
+    fn test_unit_constructor_overflow_panics() {
+        Duration::from_days(u64::MAX / 24);
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt::Write;
+
+use crate::DateTime;
+
+impl DateTime {
+    /// Formats the date-time using a `strftime`-style pattern.
+    ///
+    /// Fields are shown in the date-time's own time zone. The supported tokens are:
+    ///
+    /// * `%Y` - The year, at least 4 digits
+    /// * `%m` - The month, `01` to `12`
+    /// * `%d` - The day of the month, `01` to `31`
+    /// * `%H` - The hour, `00` to `23`
+    /// * `%M` - The minute, `00` to `59`
+    /// * `%S` - The second, `00` to `59`
+    /// * `%%` - A literal `%`
+    ///
+    /// Any other character, including an unrecognised token, is copied as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timer::DateTime;
+    ///
+    /// let datetime = DateTime::from_iso8601("2024-03-05T07:08:09Z").unwrap();
+    /// assert_eq!(datetime.format("%d/%m/%Y %H:%M"), "05/03/2024 07:08");
+    /// ```
+    pub fn format(&self, pattern: &str) -> String {
+        let fields = self.fields();
+        let mut output = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                output.push(c);
+                continue;
+            }
+
+            // Writing to a String can't fail
+            let _ = match chars.next() {
+                Some('Y') => write!(output, "{:04}", fields.year),
+                Some('m') => write!(output, "{:02}", fields.month),
+                Some('d') => write!(output, "{:02}", fields.day),
+                Some('H') => write!(output, "{:02}", fields.hour),
+                Some('M') => write!(output, "{:02}", fields.minute),
+                Some('S') => write!(output, "{:02}", fields.second),
+                Some('%') => write!(output, "%"),
+                Some(other) => write!(output, "%{}", other),
+                None => write!(output, "%"),
+            };
+        }
+
+        output
+    }
+
+    /// Formats the date-time as ISO 8601, e.g. `2024-03-05T07:08:09Z`.
+    ///
+    /// UTC date-times end in `Z`; others end in their offset, e.g. `+05:30`. As long
+    /// as the offset is within ±14:00, as [`Timezone::fixed_offset`] guarantees, the
+    /// result parses back to the same date-time with `from_iso8601`.
+    ///
+    /// [`Timezone::fixed_offset`]: crate::Timezone::fixed_offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timer::DateTime;
+    ///
+    /// let datetime = DateTime::from_iso8601("2024-03-05T07:08:09-04:00").unwrap();
+    /// assert_eq!(datetime.to_iso8601(), "2024-03-05T07:08:09-04:00");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let mut output = self.format("%Y-%m-%dT%H:%M:%S");
+        let offset = self.timezone.offset_minutes();
+        if offset == 0 {
+            output.push('Z');
+        } else {
+            let sign = if offset < 0 { '-' } else { '+' };
+            let minutes = offset.unsigned_abs();
+            let _ = write!(output, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Timezone};
+
+    #[test]
+    fn test_format_tokens() {
+        let datetime = DateTime::from_iso8601("2024-12-31T23:59:58Z").unwrap();
+
+        assert_eq!(datetime.format("%Y-%m-%d %H:%M:%S"), "2024-12-31 23:59:58");
+        assert_eq!(datetime.format("%H%%"), "23%");
+        assert_eq!(datetime.format("no tokens"), "no tokens");
+    }
+
+    #[test]
+    fn test_format_pads_fields() {
+        let datetime = DateTime::from_iso8601("0987-01-02T03:04:05Z").unwrap();
+        assert_eq!(datetime.format("%Y %m %d %H %M %S"), "0987 01 02 03 04 05");
+    }
+
+    #[test]
+    fn test_format_unknown_and_trailing_percent() {
+        let datetime = DateTime::default();
+        assert_eq!(datetime.format("%q %"), "%q %");
+    }
+
+    #[test]
+    fn test_format_uses_local_time() {
+        let datetime = DateTime::from_iso8601("2024-01-01T01:30:00+05:30").unwrap();
+        assert_eq!(datetime.format("%Y-%m-%d %H:%M"), "2024-01-01 01:30");
+    }
+
+    #[test]
+    fn test_to_iso8601() {
+        assert_eq!(DateTime::default().to_iso8601(), "1970-01-01T00:00:00Z");
+
+        let datetime = DateTime::from_iso8601("1999-12-31T23:59:59Z").unwrap();
+        assert_eq!(datetime.to_iso8601(), "1999-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn test_iso8601_round_trip() {
+        for input in [
+            "2024-02-29T12:00:00Z",
+            "1969-07-20T20:17:40Z",
+            "2024-01-01T05:30:00+05:30",
+            "2023-12-31T19:00:00-05:00",
+        ] {
+            let datetime = DateTime::from_iso8601(input).unwrap();
+            assert_eq!(datetime.to_iso8601(), input);
+            assert_eq!(DateTime::from_iso8601(&datetime.to_iso8601()), Ok(datetime));
+        }
+    }
+
+    #[test]
+    fn test_iso8601_round_trip_needs_valid_offset() {
+        let datetime = DateTime::from_iso8601("2024-01-01T00:00:00Z").unwrap();
+
+        let farthest = datetime.with_timezone(Timezone::fixed_offset(-840).unwrap());
+        assert_eq!(DateTime::from_iso8601(&farthest.to_iso8601()), Ok(farthest));
+
+        let invalid = datetime.with_timezone(Timezone::FixedOffset(1000));
+        assert_eq!(invalid.to_iso8601(), "2024-01-01T16:40:00+16:40");
+        assert_eq!(
+            DateTime::from_iso8601(&invalid.to_iso8601()),
+            Err(ParseError::InvalidOffset)
+        );
+    }
+}
@@ -1,14 +1,21 @@
 mod timezone;
 pub use timezone::*;
 
-mod time;
-pub use time::*;
-
-mod date;
-pub use date::*;
-
 mod datetime;
 pub use datetime::*;
 
 mod system_time;
 pub use system_time::*;
+
+mod calendar;
+
+mod parse;
+pub use parse::*;
+
+mod format;
+
+mod duration;
+pub use duration::*;
+
+mod weekday;
+pub use weekday::*;
@@ -0,0 +1,267 @@
+use std::{error::Error, fmt};
+
+use crate::{
+    calendar::{days_from_civil, days_in_month, MILLIS_PER_SECOND, SECONDS_PER_DAY},
+    DateTime, Timezone,
+};
+
+/// An error returned when a string can't be parsed into a `DateTime`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// The string doesn't have the expected layout of digits and separators.
+    InvalidFormat,
+    /// A field is well-formed but outside its valid range, e.g. a month of 13.
+    /// Holds the name of the field.
+    OutOfRange(&'static str),
+    /// The time zone designator is missing, malformed, or not a real UTC offset.
+    InvalidOffset,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidFormat => write!(f, "invalid date-time format"),
+            ParseError::OutOfRange(field) => write!(f, "{} is out of range", field),
+            ParseError::InvalidOffset => write!(f, "invalid time zone offset"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a fixed-width, unsigned decimal field from `s[start..end]`.
+fn number(s: &str, start: usize, end: usize) -> Result<u32, ParseError> {
+    let digits = s.get(start..end).ok_or(ParseError::InvalidFormat)?;
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseError::InvalidFormat);
+    }
+    digits.parse().map_err(|_| ParseError::InvalidFormat)
+}
+
+/// Checks that `s[index]` is the separator `expected`.
+fn separator(s: &str, index: usize, expected: u8) -> Result<(), ParseError> {
+    match s.as_bytes().get(index) {
+        Some(&byte) if byte == expected => Ok(()),
+        _ => Err(ParseError::InvalidFormat),
+    }
+}
+
+/// Parses the time zone designator: `Z`, or `+HH:MM` / `-HH:MM`.
+fn offset(designator: &str) -> Result<Timezone, ParseError> {
+    if designator == "Z" {
+        return Ok(Timezone::UTC);
+    }
+
+    let sign = match designator.as_bytes().first() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(ParseError::InvalidOffset),
+    };
+    if designator.len() != 6 || separator(designator, 3, b':').is_err() {
+        return Err(ParseError::InvalidOffset);
+    }
+    let hours = number(designator, 1, 3).map_err(|_| ParseError::InvalidOffset)?;
+    let minutes = number(designator, 4, 6).map_err(|_| ParseError::InvalidOffset)?;
+    if minutes > 59 {
+        return Err(ParseError::InvalidOffset);
+    }
+
+    // Two digits of hours keep this well within an `i16`
+    let offset = (hours * 60 + minutes) as i16;
+    Timezone::fixed_offset(sign * offset).ok_or(ParseError::InvalidOffset)
+}
+
+impl DateTime {
+    /// Parses an ISO 8601 date-time in the form `YYYY-MM-DDTHH:MM:SSZ` or
+    /// `YYYY-MM-DDTHH:MM:SS+HH:MM` (or `-HH:MM`).
+    ///
+    /// The date and time are read as local to the given offset, and the result keeps
+    /// that offset as its time zone.
+    ///
+    /// # Errors
+    ///
+    /// * `ParseError::InvalidFormat` if the layout doesn't match
+    /// * `ParseError::OutOfRange` if a field is outside its valid range
+    /// * `ParseError::InvalidOffset` if the time zone designator is missing or invalid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timer::{DateTime, ParseError};
+    ///
+    /// assert!(DateTime::from_iso8601("2024-03-15T12:30:00Z").is_ok());
+    /// assert!(DateTime::from_iso8601("2024-03-15T18:00:00+05:30").is_ok());
+    ///
+    /// assert_eq!(
+    ///     DateTime::from_iso8601("2024-13-01T00:00:00Z"),
+    ///     Err(ParseError::OutOfRange("month"))
+    /// );
+    /// ```
+    pub fn from_iso8601(s: &str) -> Result<DateTime, ParseError> {
+        let year = number(s, 0, 4)?;
+        separator(s, 4, b'-')?;
+        let month = number(s, 5, 7)?;
+        separator(s, 7, b'-')?;
+        let day = number(s, 8, 10)?;
+        separator(s, 10, b'T')?;
+        let hour = number(s, 11, 13)?;
+        separator(s, 13, b':')?;
+        let minute = number(s, 14, 16)?;
+        separator(s, 16, b':')?;
+        let second = number(s, 17, 19)?;
+        let timezone = offset(s.get(19..).ok_or(ParseError::InvalidFormat)?)?;
+
+        let year = year as i32;
+        if !(1..=12).contains(&month) {
+            return Err(ParseError::OutOfRange("month"));
+        }
+        let month = month as u8;
+        if day < 1 || day > u32::from(days_in_month(year, month)) {
+            return Err(ParseError::OutOfRange("day"));
+        }
+        if hour > 23 {
+            return Err(ParseError::OutOfRange("hour"));
+        }
+        if minute > 59 {
+            return Err(ParseError::OutOfRange("minute"));
+        }
+        if second > 59 {
+            return Err(ParseError::OutOfRange("second"));
+        }
+
+        let days = days_from_civil(year, month, day as u8);
+        let local_seconds = days * SECONDS_PER_DAY + i64::from(hour * 3_600 + minute * 60 + second);
+        let seconds = local_seconds - timezone.offset_seconds();
+
+        Ok(DateTime {
+            timestamp: seconds * MILLIS_PER_SECOND,
+            timezone,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_utc() {
+        let datetime = DateTime::from_iso8601("2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(datetime.timestamp, 1_704_067_200_000);
+        assert_eq!(datetime.timezone(), Timezone::UTC);
+
+        let epoch = DateTime::from_iso8601("1970-01-01T00:00:00Z").unwrap();
+        assert_eq!(epoch, DateTime::default());
+    }
+
+    #[test]
+    fn test_parse_positive_offset() {
+        let datetime = DateTime::from_iso8601("2024-01-01T05:30:00+05:30").unwrap();
+        assert_eq!(
+            datetime.timestamp, 1_704_067_200_000,
+            "Same instant as midnight UTC"
+        );
+        assert_eq!(datetime.timezone(), Timezone::FixedOffset(330));
+    }
+
+    #[test]
+    fn test_parse_negative_offset() {
+        let datetime = DateTime::from_iso8601("2023-12-31T19:00:00-05:00").unwrap();
+        assert_eq!(
+            datetime.timestamp, 1_704_067_200_000,
+            "Same instant as midnight UTC"
+        );
+        assert_eq!(datetime.timezone(), Timezone::FixedOffset(-300));
+    }
+
+    #[test]
+    fn test_parse_before_epoch() {
+        let datetime = DateTime::from_iso8601("1969-12-31T23:59:59Z").unwrap();
+        assert_eq!(datetime.timestamp, -1_000);
+    }
+
+    #[test]
+    fn test_parse_leap_day() {
+        assert!(DateTime::from_iso8601("2024-02-29T12:00:00Z").is_ok());
+        assert_eq!(
+            DateTime::from_iso8601("2023-02-29T12:00:00Z"),
+            Err(ParseError::OutOfRange("day"))
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        for input in [
+            "",
+            "2024-01-01",
+            "2024/01/01T00:00:00Z",
+            "2024-01-01 00:00:00Z",
+            "24-01-01T00:00:00Z",
+            "2024-1-01T00:00:00Z",
+            "2024-01-01T00:00Z",
+            "2024-0a-01T00:00:00Z",
+            "+024-01-01T00:00:00Z",
+            "2024-01-01T00:00:00ZZ",
+            "２０２４-01-01T00:00:00Z",
+        ] {
+            assert!(
+                DateTime::from_iso8601(input).is_err(),
+                "Accepted {:?}",
+                input
+            );
+        }
+        assert_eq!(
+            DateTime::from_iso8601("2024/01/01T00:00:00Z"),
+            Err(ParseError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_parse_out_of_range() {
+        let cases = [
+            ("2024-00-10T00:00:00Z", "month"),
+            ("2024-13-10T00:00:00Z", "month"),
+            ("2024-04-31T00:00:00Z", "day"),
+            ("2024-04-00T00:00:00Z", "day"),
+            ("2024-04-10T24:00:00Z", "hour"),
+            ("2024-04-10T00:60:00Z", "minute"),
+            ("2024-04-10T00:00:60Z", "second"),
+        ];
+        for (input, field) in cases {
+            assert_eq!(
+                DateTime::from_iso8601(input),
+                Err(ParseError::OutOfRange(field)),
+                "Input {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_offset() {
+        for input in [
+            "2024-01-01T00:00:00",
+            "2024-01-01T00:00:00+05",
+            "2024-01-01T00:00:00+0530",
+            "2024-01-01T00:00:00+15:00",
+            "2024-01-01T00:00:00+05:60",
+            "2024-01-01T00:00:00*05:00",
+            "2024-01-01T00:00:00z",
+        ] {
+            assert_eq!(
+                DateTime::from_iso8601(input),
+                Err(ParseError::InvalidOffset),
+                "Input {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            ParseError::OutOfRange("day").to_string(),
+            "day is out of range"
+        );
+    }
+}
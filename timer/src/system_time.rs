@@ -1,17 +1,126 @@
-use crate::DateTime;
+use std::time::{self, UNIX_EPOCH};
+
+use crate::{DateTime, Timezone};
 
 pub struct SystemTime;
 
 impl SystemTime {
-    /// Returns the current system time as a Unix timestamp in milliseconds.
+    /// Returns the current system time as a UTC `DateTime`.
     pub fn now() -> DateTime {
-        // Use the standard library's SystemTime to get the current time
-        let now = std::time::SystemTime::now();
-        // Convert to UNIX timestamp in milliseconds
-        let timestamp = now
-            .duration_since(std::time::UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_millis() as u64;
-        todo!()
+        DateTime::from_system_time(time::SystemTime::now())
+    }
+}
+
+impl DateTime {
+    /// Creates a UTC date-time from a number of milliseconds since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timer::DateTime;
+    ///
+    /// let datetime = DateTime::from_unix_millis(1_704_067_200_000);
+    /// assert_eq!(datetime.to_iso8601(), "2024-01-01T00:00:00Z");
+    /// ```
+    pub fn from_unix_millis(millis: i64) -> DateTime {
+        DateTime {
+            timestamp: millis,
+            timezone: Timezone::UTC,
+        }
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch, negative for earlier
+    /// instants.
+    pub fn unix_millis(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Converts a standard library `SystemTime` into a UTC date-time.
+    ///
+    /// Precision below a millisecond is dropped, rounding towards the past.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is too far from the Unix epoch to be represented.
+    pub fn from_system_time(time: time::SystemTime) -> DateTime {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => i128::try_from(since.as_millis()).expect("SystemTime out of range"),
+            Err(error) => {
+                let before = error.duration();
+                let whole = i128::try_from(before.as_millis()).expect("SystemTime out of range");
+                let partial = before.subsec_nanos() % 1_000_000 != 0;
+                -whole - i128::from(partial)
+            }
+        };
+
+        DateTime::from_unix_millis(i64::try_from(millis).expect("SystemTime out of range"))
+    }
+
+    /// Converts the date-time into a standard library `SystemTime`.
+    pub fn to_system_time(&self) -> time::SystemTime {
+        let offset = time::Duration::from_millis(self.timestamp.unsigned_abs());
+        if self.timestamp >= 0 {
+            UNIX_EPOCH + offset
+        } else {
+            UNIX_EPOCH - offset
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_now_is_close_to_std_now() {
+        let expected = time::SystemTime::now();
+        let now = SystemTime::now().to_system_time();
+
+        let difference = match now.duration_since(expected) {
+            Ok(difference) => difference,
+            Err(error) => error.duration(),
+        };
+        assert!(
+            difference < time::Duration::from_millis(50),
+            "{:?}",
+            difference
+        );
+    }
+
+    #[test]
+    fn test_system_time_round_trip() {
+        let original = time::SystemTime::now();
+        let datetime = DateTime::from_system_time(original);
+        let converted = datetime.to_system_time();
+
+        // Sub-millisecond precision is dropped
+        let lost = original.duration_since(converted).unwrap();
+        assert!(lost < time::Duration::from_millis(1));
+        assert_eq!(DateTime::from_system_time(converted), datetime);
+    }
+
+    #[test]
+    fn test_unix_epoch() {
+        assert_eq!(DateTime::from_system_time(UNIX_EPOCH), DateTime::default());
+        assert_eq!(DateTime::default().to_system_time(), UNIX_EPOCH);
+    }
+
+    #[test]
+    fn test_before_epoch() {
+        let time = UNIX_EPOCH - time::Duration::from_micros(1_500);
+        let datetime = DateTime::from_system_time(time);
+
+        assert_eq!(datetime.unix_millis(), -2, "Rounds towards the past");
+        assert_eq!(
+            datetime.to_system_time(),
+            UNIX_EPOCH - time::Duration::from_millis(2)
+        );
+    }
+
+    #[test]
+    fn test_unix_millis() {
+        let datetime = DateTime::from_iso8601("2024-01-01T05:30:00+05:30").unwrap();
+        assert_eq!(datetime.unix_millis(), 1_704_067_200_000);
+        assert_eq!(DateTime::from_unix_millis(1_704_067_200_000), datetime);
     }
 }
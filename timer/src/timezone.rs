@@ -1,5 +1,82 @@
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct TimeZone {
-    // pub name: String, // e.g., "America/New_York"
-    pub offset: i32, // Offset in seconds from UTC
+/// The largest UTC offset in use anywhere, `+14:00`, in minutes.
+const MAX_OFFSET_MINUTES: u16 = 14 * 60;
+
+/// The time zone a `DateTime` is displayed in.
+///
+/// Only fixed offsets from UTC are supported; there are no daylight saving rules.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum Timezone {
+    /// Coordinated Universal Time
+    #[default]
+    UTC,
+    /// A fixed offset from UTC in minutes, positive east of Greenwich
+    /// (e.g. `330` for UTC+05:30). Prefer [`Timezone::fixed_offset`], which
+    /// rejects offsets beyond ±14:00.
+    FixedOffset(i16),
+}
+
+impl Timezone {
+    /// Creates a fixed offset from UTC in minutes, positive east of Greenwich.
+    ///
+    /// Returns `None` if the offset is beyond ±14:00, which no real time zone uses
+    /// and `DateTime::from_iso8601` doesn't accept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timer::Timezone;
+    ///
+    /// assert_eq!(Timezone::fixed_offset(-300), Some(Timezone::FixedOffset(-300)));
+    /// assert_eq!(Timezone::fixed_offset(1000), None);
+    /// ```
+    pub fn fixed_offset(minutes: i16) -> Option<Timezone> {
+        if minutes.unsigned_abs() > MAX_OFFSET_MINUTES {
+            return None;
+        }
+        Some(Timezone::FixedOffset(minutes))
+    }
+
+    /// Returns the offset from UTC in minutes, positive east of Greenwich.
+    pub fn offset_minutes(&self) -> i16 {
+        match self {
+            Timezone::UTC => 0,
+            Timezone::FixedOffset(minutes) => *minutes,
+        }
+    }
+
+    /// Returns the offset from UTC in seconds.
+    pub(crate) fn offset_seconds(&self) -> i64 {
+        i64::from(self.offset_minutes()) * 60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_minutes() {
+        assert_eq!(Timezone::UTC.offset_minutes(), 0);
+        assert_eq!(Timezone::FixedOffset(330).offset_minutes(), 330);
+        assert_eq!(Timezone::FixedOffset(-300).offset_seconds(), -18_000);
+    }
+
+    #[test]
+    fn test_default_is_utc() {
+        assert_eq!(Timezone::default(), Timezone::UTC);
+    }
+
+    #[test]
+    fn test_fixed_offset_limits() {
+        assert_eq!(
+            Timezone::fixed_offset(840),
+            Some(Timezone::FixedOffset(840))
+        );
+        assert_eq!(
+            Timezone::fixed_offset(-840),
+            Some(Timezone::FixedOffset(-840))
+        );
+        assert_eq!(Timezone::fixed_offset(841), None);
+        assert_eq!(Timezone::fixed_offset(i16::MIN), None);
+    }
 }
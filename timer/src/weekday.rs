@@ -0,0 +1,101 @@
+use crate::{calendar::days_from_civil, DateTime};
+
+/// A day of the week.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Converts a day index counted from Sunday (`0`) to Saturday (`6`).
+    fn from_sunday_index(index: i64) -> Weekday {
+        match index {
+            0 => Weekday::Sunday,
+            1 => Weekday::Monday,
+            2 => Weekday::Tuesday,
+            3 => Weekday::Wednesday,
+            4 => Weekday::Thursday,
+            5 => Weekday::Friday,
+            _ => Weekday::Saturday,
+        }
+    }
+}
+
+impl DateTime {
+    /// Returns the day of the week in the date-time's time zone.
+    ///
+    /// Uses Tomohiko Sakamoto's algorithm on the year, month and day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timer::{DateTime, Weekday};
+    ///
+    /// let datetime = DateTime::from_iso8601("2000-01-01T00:00:00Z").unwrap();
+    /// assert_eq!(datetime.weekday(), Weekday::Saturday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        const MONTH_OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+        let fields = self.fields();
+        let month = usize::from(fields.month);
+        let year = i64::from(fields.year) - i64::from(month < 3);
+        let index = year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + MONTH_OFFSETS[month - 1]
+            + i64::from(fields.day);
+        Weekday::from_sunday_index(index.rem_euclid(7))
+    }
+
+    /// Returns the day of the year, `1` to `366`, in the date-time's time zone.
+    pub fn day_of_year(&self) -> u16 {
+        let fields = self.fields();
+        let days = days_from_civil(fields.year, fields.month, fields.day)
+            - days_from_civil(fields.year, 1, 1);
+        days as u16 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timezone;
+
+    fn parse(s: &str) -> DateTime {
+        DateTime::from_iso8601(s).unwrap()
+    }
+
+    #[test]
+    fn test_weekday_known_dates() {
+        assert_eq!(parse("2000-01-01T00:00:00Z").weekday(), Weekday::Saturday);
+        assert_eq!(parse("2024-07-01T12:00:00Z").weekday(), Weekday::Monday);
+        assert_eq!(parse("2024-02-29T12:00:00Z").weekday(), Weekday::Thursday);
+        assert_eq!(parse("2023-12-31T12:00:00Z").weekday(), Weekday::Sunday);
+        assert_eq!(parse("1970-01-01T00:00:00Z").weekday(), Weekday::Thursday);
+        assert_eq!(parse("1969-07-20T20:17:00Z").weekday(), Weekday::Sunday);
+    }
+
+    #[test]
+    fn test_weekday_uses_timezone() {
+        let utc = parse("2024-07-01T02:00:00Z");
+        let local = utc.with_timezone(Timezone::FixedOffset(-5 * 60));
+
+        assert_eq!(utc.weekday(), Weekday::Monday);
+        assert_eq!(local.weekday(), Weekday::Sunday);
+    }
+
+    #[test]
+    fn test_day_of_year() {
+        assert_eq!(parse("2023-01-01T00:00:00Z").day_of_year(), 1);
+        assert_eq!(parse("2023-03-01T00:00:00Z").day_of_year(), 60);
+        assert_eq!(parse("2024-03-01T00:00:00Z").day_of_year(), 61);
+        assert_eq!(parse("2023-12-31T00:00:00Z").day_of_year(), 365);
+        assert_eq!(parse("2024-12-31T00:00:00Z").day_of_year(), 366);
+    }
+}